@@ -23,7 +23,7 @@ run_demo() {
     echo -e "Rulare program..."
     echo ""
 
-    echo -e "2\n$vars\n$minterms\ngata\n\n0" | cargo run -q
+    echo -e "2\n1\n$vars\n$minterms\ngata\n\n3\n\n0" | cargo run -q
 
     echo -e "\n${YELLOW}----------------------------------------${NC}"
     echo -e "Apasă [ENTER] pentru următorul test..."
@@ -44,7 +44,7 @@ show_slide() {
     echo -e "${YELLOW}========================================${NC}"
     echo -e "Formula: ${GREEN}$form${NC}\n"
     
-    printf "1\n%s\n\n0\n" "$form" | cargo run -q
+    printf "1\n%s\n\n\n\n\n0\n" "$form" | cargo run -q
 
     echo ""
     echo -e "${YELLOW}----------------------------------------${NC}"
@@ -0,0 +1,512 @@
+use std::collections::{HashMap, HashSet};
+use colored::*;
+
+use crate::formula::{Formula, Valuation};
+use crate::minimizer::BooleanFunction;
+
+// Diagramă de decizie binară redusă și ordonată (ROBDD). Nodurile sunt partajate
+// printr-o tabelă unică, deci două funcții egale au același nod rădăcină.
+pub type NodeId = usize;
+
+pub const FALSE: NodeId = 0;
+pub const TRUE: NodeId = 1;
+
+// Nivelul terminalelor: sub orice variabilă
+const TERMINAL: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    level: usize,
+    low: NodeId,
+    high: NodeId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Implies,
+    Xor,
+    Iff,
+}
+
+impl Op {
+    fn eval(&self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Implies => !a || b,
+            Op::Xor => a != b,
+            Op::Iff => a == b,
+        }
+    }
+}
+
+// Ordinea variabilelor (de sus în jos) folosită la construcție
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarOrder {
+    // Ordinea primei apariții în formulă (sau ordinea coloanelor funcției)
+    Appearance,
+    Alphabetical,
+    Custom(Vec<String>),
+    // Toate permutările pentru cel mult BEST_ORDER_LIMIT variabile, cea cu cele mai puține noduri
+    Best,
+}
+
+const BEST_ORDER_LIMIT: usize = 7;
+
+impl VarOrder {
+    // "alpha", "best", "appearance" sau o listă de variabile separate prin virgulă
+    pub fn parse(text: &str) -> VarOrder {
+        match text.trim() {
+            "alpha" | "alfabetic" => VarOrder::Alphabetical,
+            "best" | "optim" => VarOrder::Best,
+            "appearance" | "aparitie" | "apariție" => VarOrder::Appearance,
+            list => VarOrder::Custom(list.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()).collect()),
+        }
+    }
+
+    // Ordinea concretă pentru variabilele date; variabilele lipsă din ordinea explicită vin la final
+    fn resolve(&self, vars: &[String]) -> Vec<String> {
+        match self {
+            VarOrder::Appearance | VarOrder::Best => vars.to_vec(),
+            VarOrder::Alphabetical => {
+                let mut sorted = vars.to_vec();
+                sorted.sort();
+                sorted
+            },
+            VarOrder::Custom(list) => {
+                let mut order: Vec<String> = list.iter().filter(|v| vars.contains(v)).cloned().collect();
+                order.extend(vars.iter().filter(|v| !list.contains(v)).cloned());
+                order
+            },
+        }
+    }
+}
+
+pub struct Bdd {
+    // Variabilele în ordinea nivelurilor (var_names[0] e în vârf)
+    pub var_names: Vec<String>,
+    nodes: Vec<Node>,
+    unique: HashMap<Node, NodeId>,
+    ite_cache: HashMap<(NodeId, NodeId, NodeId), NodeId>,
+    apply_cache: HashMap<(Op, NodeId, NodeId), NodeId>,
+}
+
+impl Bdd {
+    pub fn new(var_names: Vec<String>) -> Self {
+        let terminal = |v| Node { level: TERMINAL, low: v, high: v };
+        Bdd {
+            var_names,
+            nodes: vec![terminal(FALSE), terminal(TRUE)],
+            unique: HashMap::new(),
+            ite_cache: HashMap::new(),
+            apply_cache: HashMap::new(),
+        }
+    }
+
+    // BDD-ul unei formule, cu ordinea variabilelor cerută
+    pub fn from_formula(formula: &Formula, order: &VarOrder) -> (Bdd, NodeId) {
+        let vars = formula.variables();
+        Bdd::build_ordered(&vars, order, |bdd| bdd.formula(formula))
+    }
+
+    // BDD-ul funcției date prin mintermi (don't care-urile sunt considerate 0)
+    pub fn from_function(func: &BooleanFunction, order: &VarOrder) -> (Bdd, NodeId) {
+        let ones: HashSet<usize> = func.minterm_indices().into_iter().collect();
+        Bdd::build_ordered(&func.var_names, order, |bdd| bdd.truth_table(&func.var_names, &ones))
+    }
+
+    fn build_ordered(vars: &[String], order: &VarOrder, build: impl Fn(&mut Bdd) -> NodeId) -> (Bdd, NodeId) {
+        let candidates = if *order == VarOrder::Best && vars.len() <= BEST_ORDER_LIMIT {
+            permutations(vars)
+        } else {
+            vec![order.resolve(vars)]
+        };
+
+        candidates.into_iter()
+            .map(|names| {
+                let mut bdd = Bdd::new(names);
+                let root = build(&mut bdd);
+                (bdd, root)
+            })
+            .min_by_key(|(bdd, root)| bdd.size(*root))
+            .expect("Există cel puțin o ordine")
+    }
+
+    // Poziția variabilei în ordine; variabilele noi sunt adăugate la bază
+    pub fn level_of(&mut self, name: &str) -> usize {
+        match self.var_names.iter().position(|v| v == name) {
+            Some(l) => l,
+            None => {
+                self.var_names.push(name.to_string());
+                self.var_names.len() - 1
+            },
+        }
+    }
+
+    // Nodul (level, low, high) din tabela unică; testele redundante sunt eliminate
+    fn mk(&mut self, level: usize, low: NodeId, high: NodeId) -> NodeId {
+        if low == high { return low; }
+        let node = Node { level, low, high };
+        if let Some(&id) = self.unique.get(&node) { return id; }
+        self.nodes.push(node);
+        let id = self.nodes.len() - 1;
+        self.unique.insert(node, id);
+        id
+    }
+
+    pub fn var(&mut self, name: &str) -> NodeId {
+        let level = self.level_of(name);
+        self.mk(level, FALSE, TRUE)
+    }
+
+    fn level(&self, f: NodeId) -> usize {
+        self.nodes[f].level
+    }
+
+    // Cofactorii lui f față de variabila de pe nivelul dat
+    fn cofactors(&self, f: NodeId, level: usize) -> (NodeId, NodeId) {
+        let n = self.nodes[f];
+        if n.level == level { (n.low, n.high) } else { (f, f) }
+    }
+
+    // if-then-else: (f ∧ g) ∨ (¬f ∧ h)
+    pub fn ite(&mut self, f: NodeId, g: NodeId, h: NodeId) -> NodeId {
+        if f == TRUE { return g; }
+        if f == FALSE { return h; }
+        if g == h { return g; }
+        if g == TRUE && h == FALSE { return f; }
+        if let Some(&r) = self.ite_cache.get(&(f, g, h)) { return r; }
+
+        let top = self.level(f).min(self.level(g)).min(self.level(h));
+        let (f0, f1) = self.cofactors(f, top);
+        let (g0, g1) = self.cofactors(g, top);
+        let (h0, h1) = self.cofactors(h, top);
+        let low = self.ite(f0, g0, h0);
+        let high = self.ite(f1, g1, h1);
+        let r = self.mk(top, low, high);
+        self.ite_cache.insert((f, g, h), r);
+        r
+    }
+
+    // Operația binară aplicată recursiv (algoritmul Apply al lui Bryant)
+    pub fn apply(&mut self, op: Op, f: NodeId, g: NodeId) -> NodeId {
+        if f <= TRUE && g <= TRUE {
+            return if op.eval(f == TRUE, g == TRUE) { TRUE } else { FALSE };
+        }
+        if let Some(&r) = self.apply_cache.get(&(op, f, g)) { return r; }
+
+        let top = self.level(f).min(self.level(g));
+        let (f0, f1) = self.cofactors(f, top);
+        let (g0, g1) = self.cofactors(g, top);
+        let low = self.apply(op, f0, g0);
+        let high = self.apply(op, f1, g1);
+        let r = self.mk(top, low, high);
+        self.apply_cache.insert((op, f, g), r);
+        r
+    }
+
+    pub fn not(&mut self, f: NodeId) -> NodeId {
+        self.ite(f, FALSE, TRUE)
+    }
+
+    pub fn formula(&mut self, formula: &Formula) -> NodeId {
+        match formula {
+            Formula::Var(n) => self.var(n),
+            Formula::Not(inner) => {
+                let f = self.formula(inner);
+                self.not(f)
+            },
+            Formula::And(l, r) => self.binary(Op::And, l, r),
+            Formula::Or(l, r) => self.binary(Op::Or, l, r),
+            Formula::Implies(l, r) => self.binary(Op::Implies, l, r),
+        }
+    }
+
+    fn binary(&mut self, op: Op, l: &Formula, r: &Formula) -> NodeId {
+        let f = self.formula(l);
+        let g = self.formula(r);
+        self.apply(op, f, g)
+    }
+
+    // Construcție directă din tabelul de adevăr (descompunere Shannon în ordinea BDD-ului)
+    fn truth_table(&mut self, columns: &[String], ones: &HashSet<usize>) -> NodeId {
+        let positions: Vec<usize> = columns.iter().map(|c| self.level_of(c)).collect();
+        let n = columns.len();
+        // Bitul fiecărei variabile în indicele din tabel (MSB = prima coloană)
+        let mut bit_of_level = vec![0usize; self.var_names.len()];
+        for (col, &level) in positions.iter().enumerate() {
+            bit_of_level[level] = 1 << (n - 1 - col);
+        }
+        self.shannon(0, 0, &bit_of_level, ones)
+    }
+
+    fn shannon(&mut self, level: usize, idx: usize, bit_of_level: &[usize], ones: &HashSet<usize>) -> NodeId {
+        if level == bit_of_level.len() {
+            return if ones.contains(&idx) { TRUE } else { FALSE };
+        }
+        let low = self.shannon(level + 1, idx, bit_of_level, ones);
+        let high = self.shannon(level + 1, idx | bit_of_level[level], bit_of_level, ones);
+        self.mk(level, low, high)
+    }
+
+    pub fn is_sat(&self, f: NodeId) -> bool {
+        f != FALSE
+    }
+
+    pub fn is_valid(&self, f: NodeId) -> bool {
+        f == TRUE
+    }
+
+    // Numărul de atribuiri (peste toate variabilele din ordine) care fac funcția adevărată;
+    // None dacă nu încape în u128 (posibil de la 128 de variabile)
+    pub fn sat_count(&self, f: NodeId) -> Option<u128> {
+        let mut memo = HashMap::new();
+        let n = self.var_names.len();
+        let top = if f <= TRUE { n } else { self.level(f) };
+        shifted(self.count_from(f, &mut memo)?, top)
+    }
+
+    fn count_from(&self, f: NodeId, memo: &mut HashMap<NodeId, Option<u128>>) -> Option<u128> {
+        if f == FALSE { return Some(0); }
+        if f == TRUE { return Some(1); }
+        if let Some(&c) = memo.get(&f) { return c; }
+
+        let n = self.var_names.len();
+        let node = self.nodes[f];
+        let skip = |child: NodeId| {
+            let child_level = if child <= TRUE { n } else { self.level(child) };
+            child_level - node.level - 1
+        };
+        let low = self.count_from(node.low, memo).and_then(|c| shifted(c, skip(node.low)));
+        let high = self.count_from(node.high, memo).and_then(|c| shifted(c, skip(node.high)));
+        let c = low.zip(high).and_then(|(l, h)| l.checked_add(h));
+        memo.insert(f, c);
+        c
+    }
+
+    // Un model (variabilele care nu apar pe drum sunt puse pe fals)
+    pub fn any_sat(&self, f: NodeId) -> Option<Valuation> {
+        if f == FALSE { return None; }
+        let mut valuation: Valuation = self.var_names.iter().map(|v| (v.clone(), false)).collect();
+        let mut cur = f;
+        while cur > TRUE {
+            let node = self.nodes[cur];
+            let go_high = node.low == FALSE;
+            valuation.insert(self.var_names[node.level].clone(), go_high);
+            cur = if go_high { node.high } else { node.low };
+        }
+        Some(valuation)
+    }
+
+    // Nodurile interne accesibile din f, de sus în jos
+    fn reachable(&self, f: NodeId) -> Vec<NodeId> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![f];
+        let mut result = Vec::new();
+        while let Some(id) = stack.pop() {
+            if id <= TRUE || !seen.insert(id) { continue; }
+            result.push(id);
+            stack.push(self.nodes[id].high);
+            stack.push(self.nodes[id].low);
+        }
+        result.sort_by_key(|&id| (self.level(id), id));
+        result
+    }
+
+    // Numărul de noduri interne (fără terminale)
+    pub fn size(&self, f: NodeId) -> usize {
+        self.reachable(f).len()
+    }
+
+    fn node_name(&self, id: NodeId) -> String {
+        match id {
+            FALSE => "0".to_string(),
+            TRUE => "1".to_string(),
+            _ => format!("n{}", id),
+        }
+    }
+
+    pub fn print(&self, f: NodeId) {
+        println!("\n=== DIAGRAMĂ DE DECIZIE BINARĂ (ROBDD) ===");
+        println!("Ordinea variabilelor: {}", self.var_names.join(" < ").bold());
+        for id in self.reachable(f) {
+            let node = self.nodes[id];
+            println!("  {} = {} ? {} : {}", self.node_name(id).cyan(), self.var_names[node.level].bold(),
+                self.node_name(node.high), self.node_name(node.low));
+        }
+        println!("Rădăcina: {}", self.node_name(f).cyan().bold());
+        println!("{}", format!("{} noduri interne ( ? ramura 1 : ramura 0 )", self.size(f)).dimmed());
+    }
+
+    pub fn format_model(&self, model: &Valuation) -> String {
+        self.var_names.iter()
+            .map(|v| format!("{}={}", v, model.get(v).copied().unwrap_or(false) as u8))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // Satisfiabilitate, validitate, numărul de modele și un exemplu, citite direct din diagramă
+    pub fn print_summary(&self, f: NodeId) {
+        let yes_no = |b: bool| if b { "da".green().bold() } else { "nu".red().bold() };
+        println!("Satisfiabilă: {}", yes_no(self.is_sat(f)));
+        println!("Validă (tautologie): {}", yes_no(self.is_valid(f)));
+        let n = self.var_names.len();
+        match (self.sat_count(f), shifted(1, n)) {
+            (Some(count), Some(total)) => println!("Modele: {} din {}", count, total),
+            (Some(count), None) => println!("Modele: {} din 2^{}", count, n),
+            (None, _) => println!("Modele: cel puțin 2^128 din 2^{} (prea multe pentru numărare exactă)", n),
+        }
+        if let Some(model) = self.any_sat(f) {
+            println!("Exemplu de model: {}", self.format_model(&model).cyan());
+        }
+    }
+
+    // Diagrama în format Graphviz: linie plină = 1, linie punctată = 0
+    pub fn to_dot(&self, f: NodeId) -> String {
+        let mut dot = String::from("digraph bdd {\n");
+        dot.push_str("    0 [shape=box, label=\"0\"];\n    1 [shape=box, label=\"1\"];\n");
+        let nodes = self.reachable(f);
+        for &id in &nodes {
+            let node = self.nodes[id];
+            dot.push_str(&format!("    {} [shape=circle, label=\"{}\"];\n", id, self.var_names[node.level]));
+            dot.push_str(&format!("    {} -> {} [style=dashed];\n", id, node.low));
+            dot.push_str(&format!("    {} -> {};\n", id, node.high));
+        }
+        // Nodurile de pe același nivel sunt aliniate
+        for level in 0..self.var_names.len() {
+            let same: Vec<String> = nodes.iter().filter(|&&id| self.level(id) == level).map(|id| id.to_string()).collect();
+            if same.len() > 1 {
+                dot.push_str(&format!("    {{ rank=same; {} }}\n", same.join("; ")));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn permutations(items: &[String]) -> Vec<Vec<String>> {
+    if items.len() <= 1 { return vec![items.to_vec()]; }
+    let mut result = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+        for mut p in permutations(&rest) {
+            p.insert(0, first.clone());
+            result.push(p);
+        }
+    }
+    result
+}
+
+// `count << k`, sau None dacă se pierd biți
+fn shifted(count: u128, k: usize) -> Option<u128> {
+    if count == 0 {
+        Some(0)
+    } else if k < 128 && (count.leading_zeros() as usize) >= k {
+        Some(count << k)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    fn build(text: &str) -> (Bdd, NodeId) {
+        Bdd::from_formula(&parse_formula(text), &VarOrder::Appearance)
+    }
+
+    #[test]
+    fn equivalent_formulas_share_the_root() {
+        let mut bdd = Bdd::new(vec!["a".into(), "b".into(), "c".into()]);
+        let f = bdd.formula(&parse_formula("a -> (b & c)"));
+        let g = bdd.formula(&parse_formula("(!a | b) & (!a | c)"));
+        assert_eq!(f, g);
+        let h = bdd.formula(&parse_formula("a -> b"));
+        assert_ne!(f, h);
+    }
+
+    #[test]
+    fn tautologies_and_contradictions_are_terminals() {
+        let (bdd, root) = build("(a -> b) | (b -> a)");
+        assert!(bdd.is_valid(root));
+        let (bdd, root) = build("a & !a");
+        assert!(!bdd.is_sat(root));
+        assert_eq!(bdd.any_sat(root), None);
+    }
+
+    #[test]
+    fn model_count_and_witness() {
+        // a | b | c are 7 modele din 8
+        let (bdd, root) = build("a | b | c");
+        assert_eq!(bdd.sat_count(root), Some(7));
+        let model = bdd.any_sat(root).unwrap();
+        assert!(parse_formula("a | b | c").eval(&model));
+        // x1 & x3 lasă x2 liber
+        let (bdd, root) = build("x1 & (x2 | !x2) & x3");
+        assert_eq!(bdd.sat_count(root), Some(2));
+    }
+
+    #[test]
+    fn model_count_overflow_is_reported() {
+        // Cu 130 de variabile, x0 are 2^129 modele, iar x0 & ... & x3 are 2^126
+        let names: Vec<String> = (0..130).map(|i| format!("x{}", i)).collect();
+        let mut bdd = Bdd::new(names);
+        let x0 = bdd.var("x0");
+        assert_eq!(bdd.sat_count(x0), None);
+        let mut all = x0;
+        for i in 1..4 {
+            let xi = bdd.var(&format!("x{}", i));
+            all = bdd.apply(Op::And, all, xi);
+        }
+        assert_eq!(bdd.sat_count(all), Some(1 << 126));
+        // Ultima variabilă din ordine: 2^129 atribuiri deasupra ei
+        let last = bdd.var("x129");
+        assert_eq!(bdd.sat_count(last), None);
+        assert_eq!(bdd.sat_count(FALSE), Some(0));
+    }
+
+    #[test]
+    fn ite_matches_apply() {
+        let mut bdd = Bdd::new(vec!["a".into(), "b".into(), "c".into()]);
+        let (a, b, c) = (bdd.var("a"), bdd.var("b"), bdd.var("c"));
+        let ite = bdd.ite(a, b, c);
+        let ab = bdd.apply(Op::And, a, b);
+        let na = bdd.not(a);
+        let nac = bdd.apply(Op::And, na, c);
+        assert_eq!(ite, bdd.apply(Op::Or, ab, nac));
+        let xor = bdd.apply(Op::Xor, a, b);
+        let iff = bdd.apply(Op::Iff, a, b);
+        assert_eq!(bdd.not(xor), iff);
+    }
+
+    #[test]
+    fn variable_order_changes_the_size() {
+        // (a1 & b1) | (a2 & b2) | (a3 & b3): ordinea intercalată e liniară, cea separată exponențială
+        let text = "(a1 & b1) | (a2 & b2) | (a3 & b3)";
+        let formula = parse_formula(text);
+        let (good, g) = Bdd::from_formula(&formula, &VarOrder::Appearance);
+        let bad_order = VarOrder::parse("a1, a2, a3, b1, b2, b3");
+        let (bad, b) = Bdd::from_formula(&formula, &bad_order);
+        assert_eq!(good.size(g), 6);
+        assert_eq!(bad.size(b), 14);
+        let (best, r) = Bdd::from_formula(&formula, &VarOrder::Best);
+        assert_eq!(best.size(r), 6);
+        assert_eq!(best.sat_count(r), good.sat_count(g));
+    }
+
+    #[test]
+    fn function_and_formula_agree() {
+        let formula = parse_formula("(A & B) | (!A & C)");
+        let func = BooleanFunction::from_formula(&formula);
+        let (from_func, f) = Bdd::from_function(&func, &VarOrder::Alphabetical);
+        let (from_formula, g) = Bdd::from_formula(&formula, &VarOrder::Alphabetical);
+        assert_eq!(from_func.size(f), from_formula.size(g));
+        assert_eq!(from_func.sat_count(f), Some(4));
+        assert!(from_func.to_dot(f).contains("style=dashed"));
+    }
+}
@@ -0,0 +1,685 @@
+use colored::*;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+use lc::bdd::{Bdd, Op, VarOrder};
+use lc::cnf::Cnf;
+use lc::fitch::FitchProof;
+use lc::formula::{Formula, Valuation};
+use lc::normal_form::{self, NormalForm};
+use lc::post;
+use lc::resolution::{Outcome as ResolutionOutcome, Resolution};
+use lc::sat;
+use lc::sequent::{self, Sequent};
+use lc::truth_table::TruthTable;
+use lc::minimizer::export::{self, CoverOutput};
+use lc::minimizer::input;
+use lc::minimizer::multi::MultiOutputFunction;
+use lc::minimizer::pla::Pla;
+use lc::minimizer::veitch::MAX_MAP_VARS;
+use lc::minimizer::{espresso, BooleanFunction, GateKind};
+use lc::parser::parse_formula;
+use lc::tableau::{build_tableau, build_tableau_with_limits, manual, print_tree};
+use lc::tableau::certificate::{Certificate, Claim};
+use lc::tableau::stats::{Limits, Verdict};
+
+// Argumentele din linia de comandă: `lc <comandă> [opțiuni] [text]`
+struct Args {
+    raw: Vec<String>,
+}
+
+// Opțiunile care primesc o valoare (ex: `--formula "A & B"`)
+const VALUE_FLAGS: &[&str] = &["--formula", "--expr", "--csv", "--pla", "--output", "--out", "--svg", "--latex", "--dot", "--verilog", "--blif", "--order", "--equiv", "--complete", "--read", "--engine", "--file", "--certificate", "--max-nodes", "--max-depth", "--timeout"];
+
+impl Args {
+    fn new(raw: &[String]) -> Self {
+        Args { raw: raw.to_vec() }
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.raw.iter().any(|a| a == name)
+    }
+
+    fn value(&self, name: &str) -> Option<String> {
+        let pos = self.raw.iter().position(|a| a == name)?;
+        self.raw.get(pos + 1).cloned()
+    }
+
+    // Toate valorile unei opțiuni care poate apărea de mai multe ori
+    fn values(&self, name: &str) -> Vec<String> {
+        self.raw.windows(2)
+            .filter(|w| w[0] == name)
+            .map(|w| w[1].clone())
+            .collect()
+    }
+
+    // Argumentele libere (care nu sunt opțiuni sau valori de opțiuni), unite cu spațiu
+    fn free_text(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        let mut i = 0;
+        while i < self.raw.len() {
+            let a = &self.raw[i];
+            if VALUE_FLAGS.contains(&a.as_str()) {
+                i += 2;
+                continue;
+            }
+            if !a.starts_with("--") {
+                parts.push(a.clone());
+            }
+            i += 1;
+        }
+        if parts.is_empty() { None } else { Some(parts.join(" ")) }
+    }
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("{} {}", "Eroare:".red().bold(), msg);
+    process::exit(1);
+}
+
+fn print_usage() {
+    println!("{}", "Utilizare:".bold());
+    println!("  lc                                  meniul interactiv");
+    println!("  lc minimize --formula \"<formulă>\"   minimizează funcția dată de o formulă");
+    println!("  lc minimize \"<formulă>\"             (formă scurtă)");
+    println!("      --expr \"f(a,b,c) = Σm(1,3) + d(0)\"  funcția dată prin indici (sau ΠM(...))");
+    println!("      --csv <fișier>                  tabel de adevăr CSV (ultima coloană e ieșirea)");
+    println!("      --pla <fișier> [--output <nume>] fișier Berkeley PLA");
+    println!("  lc multi --pla <fișier>             minimizare multi-ieșire cu termeni partajați");
+    println!("  lc multi --expr \"s(a,b) = Σm(1,2)\" --expr \"c(a,b) = Σm(3)\"");
+    println!("      --espresso [--out <fișier.pla>] minimizare euristică (funcții mari), rezultat PLA");
+    println!("      --svg <fișier> / --latex <fișier>  exportă diagrama cu implicanții (SVG, karnaugh-map)");
+    println!("      --steps                         afișează tabelele Quine-McCluskey pas cu pas");
+    println!("      --pos                           produs de sume în loc de sumă de produse");
+    println!("      --compare                       compară costul SOP vs POS");
+    println!("      --esop                          XOR de produse (⊕), comparat cu SOP");
+    println!("      --factor                        factorizare algebrică multi-nivel a SOP-ului minim");
+    println!("      --hazards                       hazarduri statice-1 și acoperirea fără hazard");
+    println!("      --verilog <fișier> / --blif <fișier>  exportă rezultatul (minimize și multi)");
+    println!("      --nand / --nor [--dot <fișier>] circuit doar cu NAND (din SOP) / NOR (din POS), Graphviz");
+    println!("  lc bdd \"<formulă>\"                  diagrama de decizie binară (ROBDD); acceptă și --expr/--csv/--pla");
+    println!("      --order alpha|best|a,b,c        ordinea variabilelor (implicit: ordinea apariției)");
+    println!("      --equiv \"<formulă>\"           verifică echivalența cu altă formulă");
+    println!("      --dot <fișier>                  exportă diagrama în format Graphviz");
+    println!("  lc table \"<formulă>\" [--out <fișier>] tabelul de adevăr cu o coloană pe subformulă (.csv, .md, .tex)");
+    println!("  lc nf \"<formulă>\"                   formele normale FNN, FNC și FND");
+    println!("      --nnf / --cnf / --dnf           doar forma cerută");
+    println!("      --tseitin                       FNC echisatisfiabilă prin codificarea Tseitin");
+    println!("      --steps                         fiecare rescriere, cu legea aplicată");
+    println!("  lc sat \"<formulă>\"                  satisfiabilitate (sau --file <fișier> pentru formula dintr-un fișier)");
+    println!("  lc prove \"<formulă>\"                demonstrează că formula e tautologie (negația ei e nesatisfiabilă)");
+    println!("  lc entails \"A; B |= C\"              consecință logică: premisele separate prin ';', concluzia după '|='");
+    println!("      --engine tableau|cdcl|resolution  tablou semantic (implicit, cu arborele), solver CDCL (formule mari)");
+    println!("                                      sau rezoluție (respingerea numerotată; --dot <fișier> pentru graf)");
+    println!("      --max-nodes N / --max-depth N / --timeout S  limitele tabloului; la depășire verdictul e necunoscut");
+    println!("      --certificate <fișier.json>     salvează tabloul adnotat ca certificat (doar cu tabloul)");
+    println!("  lc lk \"A, B |- C\"                   demonstrație în calculul secvențial LK (arbore ASCII)");
+    println!("      --from-tableau                  derivarea obținută din tabloul închis al lui A, B, ¬C");
+    println!("      --latex <fișier>                exportă demonstrația pentru pachetul bussproofs");
+    println!("  lc manual \"<formulă>\" [--prove]     tablou construit pas cu pas de utilizator (--prove: pornește de la negație)");
+    println!("  lc verify <fișier.json>             verifică un certificat de tablou: fiecare regulă α/β și fiecare închidere");
+    println!("  lc fitch <fișier>                   verifică o demonstrație în deducția naturală (stil Fitch)");
+    println!("  lc dimacs \"<formulă>\" [--out <fișier.cnf>] FNC în format DIMACS (Tseitin; --direct prin distributivitate)");
+    println!("  lc dimacs --read <fișier.cnf> [--check] citește un fișier DIMACS; --check îl verifică prin tablou");
+    println!("  lc post \"<formulă>\"                 polinomul Zhegalkin (ANF) și clasele Post; acceptă și --expr/--csv/--pla");
+    println!("  lc post --complete \"->, 0\"         verifică dacă o mulțime de conectori e funcțional completă");
+}
+
+pub fn run(raw: &[String]) {
+    let command = raw[0].as_str();
+    let args = Args::new(&raw[1..]);
+
+    match command {
+        "minimize" => run_minimize(&args),
+        "multi" => run_multi(&args),
+        "bdd" => run_bdd(&args),
+        "post" => run_post(&args),
+        "table" => run_table(&args),
+        "nf" => run_normal_forms(&args),
+        "dimacs" => run_dimacs(&args),
+        "sat" => run_sat(&args),
+        "prove" => run_prove(&args),
+        "entails" => run_entails(&args),
+        "lk" => run_sequent(&args),
+        "fitch" => run_fitch(&args),
+        "manual" => run_manual(&args),
+        "verify" => run_verify(&args),
+        "help" | "--help" | "-h" => print_usage(),
+        _ => {
+            print_usage();
+            fail(&format!("Comandă necunoscută: {}", command));
+        }
+    }
+}
+
+fn run_minimize(args: &Args) {
+    // Espresso lucrează direct pe cuburi, fără tabelul de adevăr complet
+    if args.flag("--espresso") {
+        run_espresso(args);
+        return;
+    }
+
+    let func = match load_function(args) {
+        Ok(f) => f,
+        Err(e) => fail(&e),
+    };
+
+    func.print_veitch();
+    if args.flag("--nand") || args.flag("--nor") || args.value("--dot").is_some() {
+        run_circuit(&func, args);
+    } else if args.flag("--factor") {
+        func.simplify_factored();
+    } else if args.flag("--esop") {
+        func.simplify_esop();
+    } else if args.flag("--hazards") {
+        func.analyze_hazards();
+    } else if args.flag("--compare") {
+        func.compare_forms();
+    } else if args.flag("--pos") {
+        func.simplify_pos();
+    } else if args.flag("--steps") {
+        func.simplify_verbose();
+    } else {
+        func.simplify();
+    }
+
+    // Ieșirea exportată folosește aceeași formă ca rezultatul afișat
+    let output = if args.flag("--pos") {
+        CoverOutput { name: "f".to_string(), cover: func.pos_cover(), pos: true }
+    } else {
+        CoverOutput { name: "f".to_string(), cover: func.sop_cover(), pos: false }
+    };
+    export_map(&func, &output, args);
+    export_hdl(&func.var_names, &[output], args);
+}
+
+// Minimizare euristică: fișierele rămân cuburi, iar acoperirea calculată o dată e folosită
+// pentru afișare, diagramă, PLA și HDL
+fn run_espresso(args: &Args) {
+    let pla = load_cubes(args).unwrap_or_else(|e| fail(&e));
+    let shown = match args.value("--output") {
+        Some(name) => pla.output_index(&name).unwrap_or_else(|e| fail(&e)),
+        None => 0,
+    };
+    let wants_map = args.value("--svg").is_some() || args.value("--latex").is_some();
+    if wants_map && pla.input_names.len() > MAX_MAP_VARS {
+        fail(&format!("Diagramele Veitch sunt suportate doar pentru 1-{} variabile.", MAX_MAP_VARS));
+    }
+
+    let result = espresso::minimize_pla(&pla);
+    espresso::print_result(&pla, &result, shown);
+    if let Some(out) = args.value("--out") {
+        write_pla(&result, &out);
+    }
+
+    let outputs: Vec<CoverOutput> = result.output_names.iter().enumerate()
+        .map(|(o, name)| CoverOutput { name: name.clone(), cover: result.output_cover(o), pos: false })
+        .collect();
+    if wants_map {
+        export_map(&pla.to_function(shown), &outputs[shown], args);
+    }
+    export_hdl(&result.input_names, &outputs, args);
+}
+
+// Exportă logica minimizată ca modul Verilog și/sau model BLIF (numele vine din fișier)
+fn export_hdl(var_names: &[String], outputs: &[CoverOutput], args: &Args) {
+    if let Some(path) = args.value("--verilog") {
+        write_file(&path, &export::to_verilog(&export::module_name(&path), var_names, outputs));
+    }
+    if let Some(path) = args.value("--blif") {
+        write_file(&path, &export::to_blif(&export::module_name(&path), var_names, outputs));
+    }
+}
+
+// Circuitul NAND-NAND sau NOR-NOR; --dot scrie circuitul afișat (NAND dacă nu e cerut NOR)
+fn run_circuit(func: &BooleanFunction, args: &Args) {
+    let kind = if args.flag("--nor") { GateKind::Nor } else { GateKind::Nand };
+    let net = func.circuit(kind);
+    match kind {
+        GateKind::Nand => println!("\nSOP: {}", func.format_sop(&func.sop_cover()).bold().cyan()),
+        GateKind::Nor => println!("\nPOS: {}", func.format_pos(&func.pos_cover()).bold().cyan()),
+    }
+    net.print();
+    if let Some(path) = args.value("--dot") {
+        write_file(&path, &net.to_dot());
+    }
+}
+
+// Exportă diagrama Veitch cu acoperirea afișată (SOP sau, cu --pos, POS)
+fn export_map(func: &BooleanFunction, output: &CoverOutput, args: &Args) {
+    let svg = args.value("--svg");
+    let latex = args.value("--latex");
+    if svg.is_none() && latex.is_none() {
+        return;
+    }
+
+    let labels: Vec<String> = output.cover.iter()
+        .map(|t| if output.pos { t.to_clause_string(&func.var_names) } else { t.to_string(&func.var_names) })
+        .collect();
+
+    if let Some(path) = svg {
+        match func.to_svg(&output.cover, &labels) {
+            Ok(svg) => write_file(&path, &svg),
+            Err(e) => fail(&e),
+        }
+    }
+    if let Some(path) = latex {
+        match func.to_latex(&output.cover) {
+            Ok(tex) => write_file(&path, &tex),
+            Err(e) => fail(&e),
+        }
+    }
+}
+
+fn write_file(path: &str, content: &str) {
+    match fs::write(path, content) {
+        Ok(()) => println!("Fișier scris: {}", path.bold()),
+        Err(e) => fail(&format!("Nu pot scrie fișierul {}: {}", path, e)),
+    }
+}
+
+fn write_pla(pla: &Pla, path: &str) {
+    match pla.write(path) {
+        Ok(()) => println!("PLA scris în {}", path.bold()),
+        Err(e) => fail(&e),
+    }
+}
+
+fn run_multi(args: &Args) {
+    let result = match args.value("--pla") {
+        Some(path) => Pla::read(&path).and_then(|pla| MultiOutputFunction::from_pla(&pla)),
+        None => args.values("--expr").iter()
+            .map(|e| input::parse_index_notation(e).map(|f| (e.split('(').next().unwrap_or("").trim().to_string(), f)))
+            .collect::<Result<Vec<_>, String>>()
+            .and_then(MultiOutputFunction::from_functions),
+    };
+
+    match result {
+        Ok(multi) if !multi.outputs.is_empty() => {
+            multi.simplify();
+            if args.value("--verilog").is_some() || args.value("--blif").is_some() {
+                let outputs = multi.cover_outputs(&multi.minimize());
+                export_hdl(&multi.var_names, &outputs, args);
+            }
+        }
+        Ok(_) => fail("Nicio ieșire (folosește --pla sau --expr de mai multe ori)."),
+        Err(e) => fail(&e),
+    }
+}
+
+fn run_bdd(args: &Args) {
+    let order = args.value("--order").map(|o| VarOrder::parse(&o)).unwrap_or(VarOrder::Appearance);
+    let from_table = ["--expr", "--csv", "--pla"].iter().any(|f| args.value(f).is_some());
+
+    let (mut bdd, root) = if from_table {
+        match load_function(args) {
+            Ok(func) => Bdd::from_function(&func, &order),
+            Err(e) => fail(&e),
+        }
+    } else {
+        let input = args.value("--formula").or_else(|| args.free_text())
+            .unwrap_or_else(|| fail("Lipsește formula (ex: lc bdd \"(A & B) | C\")."));
+        let formula = parse_formula(&input);
+        println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+        Bdd::from_formula(&formula, &order)
+    };
+
+    bdd.print(root);
+    bdd.print_summary(root);
+
+    if let Some(path) = args.value("--dot") {
+        write_file(&path, &bdd.to_dot(root));
+    }
+
+    // Diagramele sunt canonice: formulele echivalente au aceeași rădăcină
+    if let Some(other) = args.value("--equiv") {
+        let formula = parse_formula(&other);
+        let g = bdd.formula(&formula);
+        if g == root {
+            println!("\n{} {}", "Echivalentă cu".green().bold(), formula.to_string().yellow());
+        } else {
+            println!("\n{} {}", "Nu este echivalentă cu".red().bold(), formula.to_string().yellow());
+            let diff = bdd.apply(Op::Xor, root, g);
+            if let Some(model) = bdd.any_sat(diff) {
+                println!("Contraexemplu: {}", bdd.format_model(&model).cyan());
+            }
+        }
+    }
+}
+
+fn run_table(args: &Args) {
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește formula (ex: lc table \"P -> (Q -> P)\")."));
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}\n", formula.to_string().yellow().bold());
+
+    let table = TruthTable::new(&formula).unwrap_or_else(|e| fail(&e));
+    table.print();
+
+    // Formatul exportului după extensie
+    if let Some(path) = args.value("--out") {
+        let content = match path.rsplit('.').next().unwrap_or("").to_lowercase().as_str() {
+            "csv" => table.to_csv(),
+            "md" => table.to_markdown(),
+            "tex" => table.to_latex(),
+            _ => fail("Extensie necunoscută pentru --out (folosește .csv, .md sau .tex)."),
+        };
+        write_file(&path, &content);
+    }
+}
+
+fn run_normal_forms(args: &Args) {
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește formula (ex: lc nf \"(P -> Q) -> R\")."));
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+
+    let mut forms: Vec<NormalForm> = [("--nnf", NormalForm::Nnf), ("--cnf", NormalForm::Cnf), ("--dnf", NormalForm::Dnf)]
+        .iter()
+        .filter(|(flag, _)| args.flag(flag))
+        .map(|&(_, form)| form)
+        .collect();
+    let tseitin = args.flag("--tseitin");
+    if forms.is_empty() && !tseitin {
+        forms = vec![NormalForm::Nnf, NormalForm::Cnf, NormalForm::Dnf];
+    }
+
+    normal_form::print_forms(&formula, &forms, args.flag("--steps"), tseitin);
+}
+
+// Motorul de decizie pentru sat/prove/entails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Engine {
+    Tableau,
+    Cdcl,
+    Resolution,
+}
+
+impl Engine {
+    fn from_args(args: &Args) -> Engine {
+        match args.value("--engine").as_deref() {
+            None | Some("tableau") => Engine::Tableau,
+            Some("cdcl") => Engine::Cdcl,
+            Some("resolution") => Engine::Resolution,
+            Some(other) => fail(&format!("Motor necunoscut: {} (folosește tableau, cdcl sau resolution)", other)),
+        }
+    }
+}
+
+// Limitele tabloului: --max-nodes, --max-depth și --timeout (secunde)
+fn limits_from_args(args: &Args) -> Limits {
+    let number = |flag: &str| args.value(flag).map(|v| v.parse::<usize>()
+        .unwrap_or_else(|_| fail(&format!("Valoare invalidă pentru {}: {}", flag, v))));
+    // try_from respinge și valorile negative, NaN, inf sau prea mari pentru Duration
+    let timeout = args.value("--timeout").map(|v| v.parse::<f64>().ok()
+        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+        .unwrap_or_else(|| fail(&format!("Valoare invalidă pentru --timeout: {}", v))));
+    Limits { max_nodes: number("--max-nodes"), max_depth: number("--max-depth"), timeout }
+}
+
+// Formula din --formula, din --file sau din textul liber
+fn formula_arg(args: &Args, example: &str) -> Formula {
+    let input = match args.value("--file") {
+        Some(path) => fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("Nu pot citi fișierul {}: {}", path, e))),
+        None => args.value("--formula").or_else(|| args.free_text())
+            .unwrap_or_else(|| fail(&format!("Lipsește formula (ex: {}).", example))),
+    };
+    parse_formula(input.trim())
+}
+
+fn format_valuation(formulas: &[Formula], model: &Valuation) -> String {
+    let mut vars: Vec<String> = Vec::new();
+    for f in formulas {
+        for v in f.variables() {
+            if !vars.contains(&v) { vars.push(v); }
+        }
+    }
+    vars.iter()
+        .map(|v| format!("{}={}", v, model.get(v).copied().unwrap_or(false) as u8))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Verifică dacă mulțimea de formule e nesatisfiabilă; afișează arborele, respingerea sau modelul găsit (`model_label`)
+fn refute(claim: Claim, args: &Args, model_label: &str) -> Verdict {
+    let formulas = claim.root_formulas();
+    match Engine::from_args(args) {
+        Engine::Tableau => {
+            let (root, stats) = build_tableau_with_limits(formulas, &limits_from_args(args));
+            println!();
+            print_tree(&root, "".to_string(), true);
+            println!();
+            stats.print();
+            if let Some(path) = args.value("--certificate") {
+                write_file(&path, &Certificate::new(claim, root).to_json());
+            }
+            stats.verdict()
+        },
+        _ if args.value("--certificate").is_some() => fail("Certificatele se pot genera doar cu --engine tableau."),
+        Engine::Cdcl => {
+            let (model, stats) = sat::satisfy(&formulas);
+            println!("{}", format!("CDCL: {} decizii, {} propagări, {} conflicte, {} clauze învățate, {} restarturi",
+                stats.decisions, stats.propagations, stats.conflicts, stats.learned, stats.restarts).dimmed());
+            if let Some(model) = &model {
+                println!("{}: {}", model_label, format_valuation(&formulas, model).cyan());
+            }
+            if model.is_some() { Verdict::Satisfiable } else { Verdict::Unsatisfiable }
+        },
+        Engine::Resolution => {
+            let resolution = Resolution::from_formulas(&formulas);
+            resolution.print();
+            if let Some(path) = args.value("--dot") {
+                write_file(&path, &resolution.to_dot());
+            }
+            match resolution.outcome {
+                ResolutionOutcome::Refuted(_) => Verdict::Unsatisfiable,
+                ResolutionOutcome::Saturated => Verdict::Satisfiable,
+                ResolutionOutcome::LimitReached => {
+                    println!("{}", "Rezoluția a atins limita de clauze; încearcă --engine cdcl.".yellow());
+                    Verdict::Unknown
+                },
+            }
+        },
+    }
+}
+
+fn print_unknown() {
+    println!("\n{}", "NECUNOSCUT (construcția s-a oprit la o limită înainte de un verdict)".yellow().bold());
+}
+
+fn run_sat(args: &Args) {
+    let formula = formula_arg(args, "lc sat \"P & (Q -> !P)\"");
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+    match refute(Claim::Unsatisfiable(vec![formula]), args, "Model") {
+        Verdict::Unsatisfiable => println!("\n{}", "NESATISFIABILĂ (contradicție)".red().bold()),
+        Verdict::Satisfiable => println!("\n{}", "SATISFIABILĂ".green().bold()),
+        Verdict::Unknown => print_unknown(),
+    }
+}
+
+fn run_prove(args: &Args) {
+    let formula = formula_arg(args, "lc prove \"((P -> Q) & P) -> Q\"");
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+    match refute(Claim::Valid(formula), args, "Contraexemplu") {
+        Verdict::Unsatisfiable => println!("\n{}", "TAUTOLOGIE DEMONSTRATĂ (negația e nesatisfiabilă)".green().bold()),
+        Verdict::Satisfiable => println!("\n{}", "NU ESTE TAUTOLOGIE".red().bold()),
+        Verdict::Unknown => print_unknown(),
+    }
+}
+
+// Premisele ⊨ concluzia dacă premisele împreună cu negația concluziei sunt nesatisfiabile
+fn run_entails(args: &Args) {
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește consecința (ex: lc entails \"P -> Q; P |= Q\")."));
+    let (premises, conclusion) = input.split_once("|=")
+        .unwrap_or_else(|| fail("Lipsește '|=' între premise și concluzie."));
+    let premises: Vec<Formula> = premises.split(';').map(str::trim).filter(|p| !p.is_empty()).map(parse_formula).collect();
+    let conclusion = parse_formula(conclusion.trim());
+
+    let shown: Vec<String> = premises.iter().map(|p| p.to_string()).collect();
+    println!("Premise: {}", shown.join(", ").yellow().bold());
+    println!("Concluzie: {}", conclusion.to_string().yellow().bold());
+
+    match refute(Claim::Entails { premises, conclusion }, args, "Contraexemplu") {
+        Verdict::Unsatisfiable => println!("\n{}", "CONSECINȚĂ LOGICĂ (premisele implică concluzia)".green().bold()),
+        Verdict::Satisfiable => println!("\n{}", "NU ESTE CONSECINȚĂ LOGICĂ".red().bold()),
+        Verdict::Unknown => print_unknown(),
+    }
+}
+
+fn run_sequent(args: &Args) {
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește secventul (ex: lc lk \"P -> Q, P |- Q\")."));
+    let goal = Sequent::parse(&input);
+    println!("Secvent: {}", goal.to_string().yellow().bold());
+
+    let proof = if args.flag("--from-tableau") {
+        // Tabloul pornește de la Γ și negațiile formulelor din Δ
+        let formulas = goal.left.iter().cloned().chain(goal.right.iter().cloned().map(Formula::not)).collect();
+        let root = build_tableau(formulas);
+        match sequent::from_tableau(&goal, &root) {
+            Some(proof) => proof,
+            None => {
+                println!("\n{}", "Tabloul are ramuri deschise: secventul nu este valid.".red().bold());
+                return;
+            },
+        }
+    } else {
+        match goal.prove() {
+            Ok(proof) => proof,
+            Err(leaf) => {
+                println!("\n{}", "Secventul nu este demonstrabil.".red().bold());
+                println!("Frunză fără axiomă: {}", leaf.to_string().cyan());
+                println!("{}", "(contraexemplu: variabilele din stânga adevărate, cele din dreapta false)".dimmed());
+                return;
+            },
+        }
+    };
+
+    println!("\n{}", proof.to_ascii());
+    println!("{}", format!("{} secvente în derivare", proof.size()).dimmed());
+    if let Some(path) = args.value("--latex") {
+        write_file(&path, &proof.to_latex());
+    }
+}
+
+fn run_manual(args: &Args) {
+    let formula = formula_arg(args, "lc manual \"(P -> Q) -> (!Q -> !P)\" --prove");
+    let formula = if args.flag("--prove") { Formula::not(formula) } else { formula };
+    println!("Formula de lucru: {}", formula.to_string().yellow().bold());
+    manual::run(vec![formula]);
+}
+
+fn run_fitch(args: &Args) {
+    let path = args.value("--file").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește fișierul cu demonstrația (ex: lc fitch dem.txt)."));
+    let proof = FitchProof::read_file(&path).unwrap_or_else(|e| fail(&e));
+    if !proof.print_check() {
+        process::exit(1);
+    }
+}
+
+// Verifică un certificat JSON independent de construcția tabloului
+fn run_verify(args: &Args) {
+    let path = args.value("--file").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește fișierul cu certificatul (ex: lc verify dem.json)."));
+    let certificate = Certificate::read_file(&path).unwrap_or_else(|e| fail(&e));
+    if !certificate.print_check() {
+        process::exit(1);
+    }
+}
+
+fn run_dimacs(args: &Args) {
+    if let Some(path) = args.value("--read") {
+        let cnf = Cnf::read_dimacs(&path).unwrap_or_else(|e| fail(&e));
+        println!("{} variabile, {} clauze", cnf.num_vars(), cnf.clauses.len());
+        println!("Formula: {}", cnf.to_formula().to_string().yellow().bold());
+        if args.flag("--check") {
+            // Tabloul pornește de la clauze, fiecare ca formulă separată pe ramura inițială
+            let (_, stats) = build_tableau_with_limits(cnf.clause_formulas(), &limits_from_args(args));
+            stats.print();
+            match stats.verdict() {
+                Verdict::Unsatisfiable => println!("{}", "NESATISFIABILĂ (toate ramurile tabloului sunt închise)".red().bold()),
+                Verdict::Satisfiable => println!("{}", "SATISFIABILĂ (tabloul are o ramură deschisă)".green().bold()),
+                Verdict::Unknown => print_unknown(),
+            }
+        }
+        return;
+    }
+
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .unwrap_or_else(|| fail("Lipsește formula (ex: lc dimacs \"(P -> Q) -> R\" --out f.cnf)."));
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+
+    let mut cnf = if args.flag("--direct") {
+        let size = normal_form::estimated_size(&formula, true);
+        if size > normal_form::DISTRIBUTION_LIMIT {
+            fail(&format!("Distributivitatea ar produce {} clauze (limita e {}); folosește codificarea Tseitin.", size, normal_form::DISTRIBUTION_LIMIT));
+        }
+        Cnf::from_cnf_formula(&normal_form::to_cnf(&formula)).unwrap_or_else(|e| fail(&e))
+    } else {
+        Cnf::tseitin(&formula)
+    };
+    cnf.simplify();
+
+    match args.value("--out") {
+        Some(path) => {
+            println!("{} variabile, {} clauze", cnf.num_vars(), cnf.clauses.len());
+            write_file(&path, &cnf.to_dimacs(Some(&formula)));
+        },
+        None => print!("\n{}", cnf.to_dimacs(Some(&formula))),
+    }
+}
+
+fn run_post(args: &Args) {
+    if let Some(set) = args.value("--complete") {
+        match post::parse_connectives(&set) {
+            Ok(connectives) => { post::check_completeness(&connectives); },
+            Err(e) => fail(&e),
+        }
+        return;
+    }
+
+    match load_function(args) {
+        Ok(func) => post::analyze(&func),
+        Err(e) => fail(&e),
+    }
+}
+
+// Funcția de minimizat ca PLA: fișierele sunt citite ca cuburi, formula și notația
+// cu indici trec prin tabelul de adevăr (oricum enumerat la construire)
+fn load_cubes(args: &Args) -> Result<Pla, String> {
+    if let Some(path) = args.value("--pla") {
+        return Pla::read(&path);
+    }
+    if let Some(path) = args.value("--csv") {
+        return input::read_csv_cubes(&path);
+    }
+    load_function(args).map(|func| Pla::from_function(&func, "f"))
+}
+
+// Funcția de minimizat, din formulă, notație cu indici sau fișier
+fn load_function(args: &Args) -> Result<BooleanFunction, String> {
+    if let Some(expr) = args.value("--expr") {
+        return input::parse_index_notation(&expr);
+    }
+    if let Some(path) = args.value("--csv") {
+        return input::read_csv(&path);
+    }
+    if let Some(path) = args.value("--pla") {
+        let pla = Pla::read(&path)?;
+        let output = match args.value("--output") {
+            Some(name) => pla.output_index(&name)?,
+            None => 0,
+        };
+        return Ok(pla.to_function(output));
+    }
+
+    let input = args.value("--formula").or_else(|| args.free_text())
+        .ok_or("Lipsește formula (ex: lc minimize --formula \"(A & B) | C\").")?;
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+    Ok(BooleanFunction::from_formula(&formula))
+}
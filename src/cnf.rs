@@ -0,0 +1,341 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+use crate::formula::Formula;
+
+// Formulă în FNC ca mulțime de clauze. Literalii sunt numerotați ca în DIMACS:
+// variabila i (de la 1) este `i`, negația ei `-i`; numele sunt în `var_names[i - 1]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cnf {
+    pub var_names: Vec<String>,
+    pub clauses: Vec<Vec<i32>>,
+}
+
+impl Cnf {
+    pub fn new(var_names: Vec<String>) -> Self {
+        Cnf { var_names, clauses: Vec::new() }
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.var_names.len()
+    }
+
+    // Numărul variabilei cu numele dat; variabilele noi sunt adăugate la final
+    pub fn var(&mut self, name: &str) -> i32 {
+        match self.var_names.iter().position(|v| v == name) {
+            Some(i) => i as i32 + 1,
+            None => {
+                self.var_names.push(name.to_string());
+                self.var_names.len() as i32
+            },
+        }
+    }
+
+    // Clauzele unei formule care este deja în FNC (conjuncție de disjuncții de literali)
+    pub fn from_cnf_formula(f: &Formula) -> Result<Cnf, String> {
+        let mut cnf = Cnf::new(f.variables());
+        let mut clauses = Vec::new();
+        collect(f, true, &mut clauses);
+        for clause in clauses {
+            let mut lits = Vec::new();
+            let mut parts = Vec::new();
+            collect(clause, false, &mut parts);
+            for lit in parts {
+                lits.push(match lit {
+                    Formula::Var(n) => cnf.var(n),
+                    Formula::Not(inner) => match &**inner {
+                        Formula::Var(n) => -cnf.var(n),
+                        _ => return Err(format!("Formula nu este în FNC: {}", f)),
+                    },
+                    _ => return Err(format!("Formula nu este în FNC: {}", f)),
+                });
+            }
+            cnf.clauses.push(lits);
+        }
+        Ok(cnf)
+    }
+
+    // Codificarea Tseitin: o variabilă nouă pentru fiecare subformulă compusă, echivalentă cu ea.
+    // Rezultatul e echisatisfiabil cu formula și are dimensiune liniară.
+    pub fn tseitin(f: &Formula) -> Cnf {
+        Cnf::tseitin_with_definitions(f).0
+    }
+
+    // Ca `tseitin`, plus definiția fiecărei variabile auxiliare (ex: _t2 ≡ _t1 ∧ c)
+    pub fn tseitin_with_definitions(f: &Formula) -> (Cnf, Vec<(String, Formula)>) {
+        let mut cnf = Cnf::new(f.variables());
+        let mut names: HashMap<Formula, i32> = HashMap::new();
+        let mut definitions = Vec::new();
+        let root = cnf.tseitin_var(f, &mut names, &mut definitions);
+        cnf.clauses.push(vec![root]);
+        (cnf, definitions)
+    }
+
+    fn tseitin_var(&mut self, f: &Formula, names: &mut HashMap<Formula, i32>, definitions: &mut Vec<(String, Formula)>) -> i32 {
+        if let Formula::Var(n) = f {
+            return self.var(n);
+        }
+        if let Some(&x) = names.get(f) {
+            return x;
+        }
+
+        let (definition, x) = match f {
+            Formula::Var(_) => unreachable!(),
+            Formula::Not(inner) => {
+                let a = self.tseitin_var(inner, names, definitions);
+                let x = self.fresh_var();
+                // x ↔ ¬a
+                self.clauses.push(vec![-x, -a]);
+                self.clauses.push(vec![x, a]);
+                (Formula::not(self.name_formula(a)), x)
+            },
+            Formula::And(l, r) => {
+                let (a, b) = (self.tseitin_var(l, names, definitions), self.tseitin_var(r, names, definitions));
+                let x = self.fresh_var();
+                // x ↔ a ∧ b
+                self.clauses.push(vec![-x, a]);
+                self.clauses.push(vec![-x, b]);
+                self.clauses.push(vec![x, -a, -b]);
+                (Formula::and(self.name_formula(a), self.name_formula(b)), x)
+            },
+            Formula::Or(l, r) => {
+                let (a, b) = (self.tseitin_var(l, names, definitions), self.tseitin_var(r, names, definitions));
+                let x = self.fresh_var();
+                // x ↔ a ∨ b
+                self.clauses.push(vec![-x, a, b]);
+                self.clauses.push(vec![x, -a]);
+                self.clauses.push(vec![x, -b]);
+                (Formula::or(self.name_formula(a), self.name_formula(b)), x)
+            },
+            Formula::Implies(l, r) => {
+                let (a, b) = (self.tseitin_var(l, names, definitions), self.tseitin_var(r, names, definitions));
+                let x = self.fresh_var();
+                // x ↔ (a → b)
+                self.clauses.push(vec![-x, -a, b]);
+                self.clauses.push(vec![x, a]);
+                self.clauses.push(vec![x, -b]);
+                (Formula::implies(self.name_formula(a), self.name_formula(b)), x)
+            },
+        };
+        names.insert(f.clone(), x);
+        definitions.push((self.var_names[x as usize - 1].clone(), definition));
+        x
+    }
+
+    fn name_formula(&self, v: i32) -> Formula {
+        Formula::var(&self.var_names[v as usize - 1])
+    }
+
+    // Variabilă auxiliară cu un nume care nu apare deja
+    fn fresh_var(&mut self) -> i32 {
+        let mut k = 1;
+        while self.var_names.contains(&format!("_t{}", k)) {
+            k += 1;
+        }
+        self.var(&format!("_t{}", k))
+    }
+
+    // Elimină literalii repetați, clauzele tautologice (x ∨ ¬x) și clauzele duplicate
+    pub fn simplify(&mut self) {
+        for clause in &mut self.clauses {
+            clause.sort_by_key(|l| (l.abs(), *l));
+            clause.dedup();
+        }
+        self.clauses.retain(|c| !c.windows(2).any(|w| w[0] == -w[1]));
+        // Clauzele sunt deja sortate, deci două clauze egale au aceeași cheie
+        let mut seen = HashSet::new();
+        self.clauses.retain(|c| seen.insert(c.clone()));
+    }
+
+    pub fn literal_name(&self, lit: i32) -> String {
+        let name = &self.var_names[lit.unsigned_abs() as usize - 1];
+        if lit < 0 { format!("¬{}", name) } else { name.clone() }
+    }
+
+    pub fn clause_string(&self, clause: &[i32]) -> String {
+        if clause.is_empty() { return "□".to_string(); }
+        format!("{{{}}}", clause.iter().map(|&l| self.literal_name(l)).collect::<Vec<_>>().join(", "))
+    }
+
+    // Ex: "{¬a, b}, {c}"; mulțimea vidă de clauze este adevărată
+    pub fn to_set_string(&self) -> String {
+        if self.clauses.is_empty() { return "∅ (adevărat)".to_string(); }
+        self.clauses.iter().map(|c| self.clause_string(c)).collect::<Vec<_>>().join(", ")
+    }
+
+    // Fiecare clauză ca disjuncție; clauza vidă devine `p ∧ ¬p` (formula nu are constante)
+    pub fn clause_formulas(&self) -> Vec<Formula> {
+        let p = Formula::var(self.var_names.first().map(|s| s.as_str()).unwrap_or("p"));
+        self.clauses.iter()
+            .map(|clause| {
+                clause.iter()
+                    .map(|&l| {
+                        let v = Formula::var(&self.var_names[l.unsigned_abs() as usize - 1]);
+                        if l < 0 { Formula::not(v) } else { v }
+                    })
+                    .reduce(Formula::or)
+                    .unwrap_or_else(|| Formula::and(p.clone(), Formula::not(p.clone())))
+            })
+            .collect()
+    }
+
+    // Conjuncția clauzelor; mulțimea vidă de clauze devine `p ∨ ¬p`
+    pub fn to_formula(&self) -> Formula {
+        self.clause_formulas().into_iter().reduce(Formula::and).unwrap_or_else(|| {
+            let p = Formula::var(self.var_names.first().map(|s| s.as_str()).unwrap_or("p"));
+            Formula::or(p.clone(), Formula::not(p))
+        })
+    }
+
+    // Formatul DIMACS CNF; antetul de comentarii leagă numerele de numele variabilelor
+    pub fn to_dimacs(&self, source: Option<&Formula>) -> String {
+        let mut out = String::from("c Generat de lc\n");
+        if let Some(f) = source {
+            out.push_str(&format!("c formula: {}\n", f));
+        }
+        for (i, name) in self.var_names.iter().enumerate() {
+            out.push_str(&format!("c var {} = {}\n", i + 1, name));
+        }
+        out.push_str(&format!("p cnf {} {}\n", self.var_names.len(), self.clauses.len()));
+        for clause in &self.clauses {
+            let lits: Vec<String> = clause.iter().map(|l| l.to_string()).collect();
+            if lits.is_empty() {
+                out.push_str("0\n");
+            } else {
+                out.push_str(&format!("{} 0\n", lits.join(" ")));
+            }
+        }
+        out
+    }
+
+    pub fn read_dimacs(path: &str) -> Result<Cnf, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Nu pot citi fișierul {}: {}", path, e))?;
+        Cnf::parse_dimacs(&text)
+    }
+
+    // Citește DIMACS: comentariile `c var N = nume` dau numele variabilelor, restul se numesc xN.
+    // Clauzele se pot întinde pe mai multe linii și se termină cu 0; `%` încheie fișierul (SATLIB).
+    pub fn parse_dimacs(text: &str) -> Result<Cnf, String> {
+        let mut header: Option<(usize, usize)> = None;
+        let mut names: HashMap<usize, String> = HashMap::new();
+        let mut clauses = Vec::new();
+        let mut current = Vec::new();
+
+        for (line_no, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() { continue; }
+            if line.starts_with('%') { break; }
+            if let Some(comment) = line.strip_prefix('c') {
+                let parts: Vec<&str> = comment.split_whitespace().collect();
+                if let ["var", num, "=", name] = parts.as_slice() {
+                    if let Ok(n) = num.parse() {
+                        names.insert(n, name.to_string());
+                    }
+                }
+                continue;
+            }
+            if line.starts_with('p') {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                match parts.as_slice() {
+                    ["p", "cnf", vars, count] => {
+                        let vars = vars.parse().map_err(|_| format!("Linia {}: număr de variabile invalid", line_no + 1))?;
+                        let count = count.parse().map_err(|_| format!("Linia {}: număr de clauze invalid", line_no + 1))?;
+                        header = Some((vars, count));
+                    },
+                    _ => return Err(format!("Linia {}: antet invalid (așteptam 'p cnf <variabile> <clauze>')", line_no + 1)),
+                }
+                continue;
+            }
+
+            let (num_vars, _) = header.ok_or(format!("Linia {}: clauză înainte de antetul 'p cnf'", line_no + 1))?;
+            for token in line.split_whitespace() {
+                let lit: i32 = token.parse().map_err(|_| format!("Linia {}: literal invalid '{}'", line_no + 1, token))?;
+                if lit == 0 {
+                    clauses.push(std::mem::take(&mut current));
+                } else if lit.unsigned_abs() as usize > num_vars {
+                    return Err(format!("Linia {}: variabila {} depășește numărul declarat ({})", line_no + 1, lit.abs(), num_vars));
+                } else {
+                    current.push(lit);
+                }
+            }
+        }
+
+        let (num_vars, count) = header.ok_or("Lipsește antetul 'p cnf <variabile> <clauze>'")?;
+        if !current.is_empty() {
+            clauses.push(current);
+        }
+        if clauses.len() != count {
+            return Err(format!("Antetul declară {} clauze, dar fișierul are {}", count, clauses.len()));
+        }
+
+        let var_names = (1..=num_vars).map(|i| names.remove(&i).unwrap_or_else(|| format!("x{}", i))).collect();
+        Ok(Cnf { var_names, clauses })
+    }
+}
+
+// Aplatizează o conjuncție (and = true) sau disjuncție în operanzii ei
+fn collect<'a>(f: &'a Formula, and: bool, out: &mut Vec<&'a Formula>) {
+    match f {
+        Formula::And(l, r) if and => {
+            collect(l, and, out);
+            collect(r, and, out);
+        },
+        Formula::Or(l, r) if !and => {
+            collect(l, and, out);
+            collect(r, and, out);
+        },
+        _ => out.push(f),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    #[test]
+    fn simplify_removes_duplicates_and_tautologies() {
+        let mut cnf = Cnf::new(vec!["a".into(), "b".into(), "c".into()]);
+        cnf.clauses = vec![vec![2, -1, 2], vec![-1, 2], vec![1, 3, -1], vec![3], vec![3, 3]];
+        cnf.simplify();
+        assert_eq!(cnf.clauses, vec![vec![-1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn dimacs_round_trip_keeps_names_and_clauses() {
+        let f = parse_formula("(p -> q) & (q | !r)");
+        let cnf = Cnf::tseitin(&f);
+        let text = cnf.to_dimacs(Some(&f));
+        assert!(text.contains(&format!("p cnf {} {}\n", cnf.num_vars(), cnf.clauses.len())));
+        assert!(text.contains("c var 1 = p\n"));
+        assert_eq!(Cnf::parse_dimacs(&text).unwrap(), cnf);
+    }
+
+    #[test]
+    fn dimacs_reader_accepts_benchmark_files() {
+        // Clauze pe mai multe linii, clauza vidă și terminatorul `%` din SATLIB
+        let text = "c exemplu\np cnf 3 3\n1 -2\n 3 0 -1 0\n0\n%\n0\n";
+        let cnf = Cnf::parse_dimacs(text).unwrap();
+        assert_eq!(cnf.var_names, vec!["x1", "x2", "x3"]);
+        assert_eq!(cnf.clauses, vec![vec![1, -2, 3], vec![-1], vec![]]);
+        assert_eq!(cnf.to_set_string(), "{x1, ¬x2, x3}, {¬x1}, □");
+    }
+
+    #[test]
+    fn dimacs_errors() {
+        let err = |text: &str| Cnf::parse_dimacs(text).expect_err("DIMACS invalid acceptat");
+        assert!(err("1 2 0\n").contains("înainte de antetul"));
+        assert!(err("p cnf 2 1\n1 3 0\n").contains("depășește"));
+        assert!(err("p cnf 2 2\n1 2 0\n").contains("declară 2 clauze"));
+        assert!(err("p dnf 2 1\n").contains("antet invalid"));
+        assert!(err("p cnf 2 1\n1 a 0\n").contains("literal invalid"));
+    }
+
+    #[test]
+    fn cnf_formula_clauses() {
+        let cnf = Cnf::from_cnf_formula(&parse_formula("(a | !b) & c")).unwrap();
+        assert_eq!(cnf.clauses, vec![vec![1, -2], vec![3]]);
+        assert_eq!(Cnf::from_cnf_formula(&cnf.to_formula()).unwrap(), cnf);
+        assert!(Cnf::from_cnf_formula(&parse_formula("a | (b & c)")).is_err());
+    }
+}
@@ -0,0 +1,520 @@
+use colored::*;
+use std::fmt;
+use std::fs;
+
+use crate::formula::Formula;
+use crate::parser::try_parse_formula;
+
+// Demonstrații în deducția naturală, stil Fitch. Formatul fișierului, câte o linie:
+//
+//   1 | P -> Q        ; Prem
+//   2 | | P           ; Ip
+//   3 | | Q           ; ->E 1,2
+//   4 | P -> Q       ; ->I 2-3
+//
+// Numărul liniei, câte o bară `|` pentru fiecare nivel (demonstrația principală are una),
+// formula și, după `;`, justificarea: regula și liniile citate (`a,b` sau intervale `i-j`
+// pentru subdemonstrații). Liniile goale, comentariile `#` și separatoarele `|---` sunt ignorate.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitchRule {
+    Premise,
+    Hypothesis,
+    Reiteration,
+    AndIntro,
+    AndElim,
+    OrIntro,
+    OrElim,
+    ImpliesIntro,
+    ImpliesElim,
+    NotIntro,
+    // Din A și ¬A se obține orice formulă
+    NotElim,
+    DoubleNegationElim,
+    Raa,
+}
+
+impl FitchRule {
+    pub fn parse(text: &str) -> Option<FitchRule> {
+        let rule = match text.to_lowercase().as_str() {
+            "prem" | "premisa" | "premisă" => FitchRule::Premise,
+            "ip" | "ipoteza" | "ipoteză" | "hyp" | "asm" => FitchRule::Hypothesis,
+            "r" | "reit" => FitchRule::Reiteration,
+            "∧i" | "&i" | "^i" => FitchRule::AndIntro,
+            "∧e" | "&e" | "^e" => FitchRule::AndElim,
+            "∨i" | "|i" | "vi" => FitchRule::OrIntro,
+            "∨e" | "|e" | "ve" => FitchRule::OrElim,
+            "→i" | "->i" => FitchRule::ImpliesIntro,
+            "→e" | "->e" | "mp" => FitchRule::ImpliesElim,
+            "¬i" | "!i" | "~i" => FitchRule::NotIntro,
+            "¬e" | "!e" | "~e" => FitchRule::NotElim,
+            "¬¬e" | "!!e" | "~~e" | "dne" => FitchRule::DoubleNegationElim,
+            "raa" => FitchRule::Raa,
+            _ => return None,
+        };
+        Some(rule)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FitchRule::Premise => "Prem",
+            FitchRule::Hypothesis => "Ip",
+            FitchRule::Reiteration => "Reit",
+            FitchRule::AndIntro => "∧I",
+            FitchRule::AndElim => "∧E",
+            FitchRule::OrIntro => "∨I",
+            FitchRule::OrElim => "∨E",
+            FitchRule::ImpliesIntro => "→I",
+            FitchRule::ImpliesElim => "→E",
+            FitchRule::NotIntro => "¬I",
+            FitchRule::NotElim => "¬E",
+            FitchRule::DoubleNegationElim => "¬¬E",
+            FitchRule::Raa => "RAA",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Citation {
+    Line(usize),
+    Range(usize, usize),
+}
+
+impl fmt::Display for Citation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Citation::Line(n) => write!(f, "{}", n),
+            Citation::Range(i, j) => write!(f, "{}-{}", i, j),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FitchLine {
+    pub number: usize,
+    // Numărul de bare (1 = demonstrația principală)
+    pub depth: usize,
+    pub formula: Formula,
+    pub rule: FitchRule,
+    pub citations: Vec<Citation>,
+    // Ipotezele subdemonstrațiilor deschise la această linie (indici în `lines`), din exterior spre interior
+    scopes: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FitchError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for FitchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Linia {}: {}", self.line, self.message)
+    }
+}
+
+pub struct FitchProof {
+    pub lines: Vec<FitchLine>,
+}
+
+fn parse_citations(text: &str, line: usize) -> Result<Vec<Citation>, FitchError> {
+    let error = |part: &str| FitchError { line, message: format!("citare invalidă '{}'", part) };
+    text.split(',').map(str::trim).filter(|p| !p.is_empty())
+        .map(|part| match part.split_once('-') {
+            Some((i, j)) => match (i.trim().parse(), j.trim().parse()) {
+                (Ok(i), Ok(j)) => Ok(Citation::Range(i, j)),
+                _ => Err(error(part)),
+            },
+            None => part.parse().map(Citation::Line).map_err(|_| error(part)),
+        })
+        .collect()
+}
+
+fn is_contradictory(a: &Formula, b: &Formula) -> bool {
+    matches!(b, Formula::Not(inner) if **inner == *a) || matches!(a, Formula::Not(inner) if **inner == *b)
+}
+
+impl FitchProof {
+    pub fn parse(text: &str) -> Result<FitchProof, FitchError> {
+        let mut lines: Vec<FitchLine> = Vec::new();
+        let mut base_depth = None;
+        let mut scopes: Vec<usize> = Vec::new();
+
+        for raw in text.lines() {
+            let raw = raw.trim();
+            if raw.is_empty() || raw.starts_with('#') || raw.chars().all(|c| matches!(c, '|' | '-' | '_' | ' ')) {
+                continue;
+            }
+            let expected = lines.last().map(|l| l.number + 1).unwrap_or(1);
+            let digits: String = raw.chars().take_while(|c| c.is_ascii_digit()).collect();
+            let number: usize = digits.parse().map_err(|_| FitchError { line: expected, message: format!("linia nu începe cu un număr: '{}'", raw) })?;
+            if number != expected {
+                return Err(FitchError { line: number, message: format!("numerotare greșită (așteptam linia {})", expected) });
+            }
+
+            let rest = raw[digits.len()..].trim_start();
+            let depth = rest.chars().take_while(|&c| c == '|' || c == ' ').filter(|&c| c == '|').count();
+            let rest = rest.trim_start_matches(['|', ' ']);
+            let (formula_text, justification) = rest.split_once(';')
+                .ok_or(FitchError { line: number, message: "lipsește justificarea (după ';')".to_string() })?;
+            let formula = try_parse_formula(formula_text.trim()).map_err(|e| FitchError { line: number, message: format!("formulă invalidă: {}", e) })?;
+
+            let justification = justification.trim();
+            let (rule_text, cited) = justification.split_once(' ').unwrap_or((justification, ""));
+            let rule = FitchRule::parse(rule_text)
+                .ok_or(FitchError { line: number, message: format!("regulă necunoscută '{}'", rule_text) })?;
+            let citations = parse_citations(cited, number)?;
+
+            // Nivelul liniei față de demonstrația principală; o ipoteză deschide o cutie nouă
+            // (o demonstrație fără premise poate începe direct cu o ipoteză)
+            let base = *base_depth.get_or_insert(if rule == FitchRule::Hypothesis { depth.saturating_sub(1) } else { depth });
+            let level = depth.checked_sub(base).ok_or(FitchError { line: number, message: "indentare sub nivelul demonstrației principale".to_string() })?;
+            if rule == FitchRule::Hypothesis {
+                if level == 0 || scopes.len() < level - 1 {
+                    return Err(FitchError { line: number, message: "o ipoteză trebuie să deschidă o subdemonstrație (o bară în plus)".to_string() });
+                }
+                scopes.truncate(level - 1);
+                scopes.push(lines.len());
+            } else {
+                if scopes.len() < level {
+                    return Err(FitchError { line: number, message: "indentare prea mare: subdemonstrația trebuie să înceapă cu o ipoteză".to_string() });
+                }
+                scopes.truncate(level);
+            }
+
+            lines.push(FitchLine { number, depth, formula, rule, citations, scopes: scopes.clone() });
+        }
+
+        if lines.is_empty() {
+            return Err(FitchError { line: 0, message: "demonstrația nu are nicio linie".to_string() });
+        }
+        Ok(FitchProof { lines })
+    }
+
+    pub fn read_file(path: &str) -> Result<FitchProof, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Nu pot citi fișierul {}: {}", path, e))?;
+        FitchProof::parse(&text).map_err(|e| e.to_string())
+    }
+
+    // Linia `k` poate fi citată la linia `m` dacă e înaintea ei și nu se află într-o cutie deja închisă
+    fn line_at(&self, m: usize, k: usize) -> Result<&Formula, String> {
+        if k == m + 1 {
+            return Err("o linie nu se poate cita pe ea însăși".to_string());
+        }
+        if k == 0 || k > m {
+            return Err(format!("linia {} nu există înaintea liniei curente", k));
+        }
+        let (line, current) = (&self.lines[k - 1], &self.lines[m]);
+        if !current.scopes.starts_with(&line.scopes) {
+            return Err(format!("linia {} se află într-o subdemonstrație închisă și nu mai e accesibilă", k));
+        }
+        Ok(&line.formula)
+    }
+
+    // Subdemonstrația i-j citată la linia `m`: ipoteza și ultima formulă
+    fn subproof_at(&self, m: usize, i: usize, j: usize) -> Result<(&Formula, &Formula, Vec<&Formula>), String> {
+        if i == 0 || i > j || j > m {
+            return Err(format!("intervalul {}-{} nu e o subdemonstrație dinaintea liniei curente", i, j));
+        }
+        let (start, end, current) = (&self.lines[i - 1], &self.lines[j - 1], &self.lines[m]);
+        if start.rule != FitchRule::Hypothesis {
+            return Err(format!("linia {} nu e o ipoteză, deci nu începe o subdemonstrație", i));
+        }
+        if end.scopes != start.scopes || self.lines.get(j).is_some_and(|next| next.scopes.starts_with(&start.scopes)) {
+            return Err(format!("subdemonstrația care începe la linia {} nu se termină la linia {}", i, j));
+        }
+        let outer = &start.scopes[..start.scopes.len() - 1];
+        if !current.scopes.starts_with(outer) || current.scopes.contains(&(i - 1)) {
+            return Err(format!("subdemonstrația {}-{} nu e accesibilă de aici", i, j));
+        }
+        let body = self.lines[i - 1..j].iter().filter(|l| l.scopes == start.scopes).map(|l| &l.formula).collect();
+        Ok((&start.formula, &end.formula, body))
+    }
+
+    fn lines_cited(&self, m: usize, count: usize) -> Result<Vec<&Formula>, String> {
+        let citations = &self.lines[m].citations;
+        if citations.len() != count || citations.iter().any(|c| matches!(c, Citation::Range(_, _))) {
+            return Err(format!("{} cere {} linii citate", self.lines[m].rule.name(), count));
+        }
+        citations.iter().map(|c| match c {
+            Citation::Line(k) => self.line_at(m, *k),
+            Citation::Range(_, _) => unreachable!(),
+        }).collect()
+    }
+
+    fn subproof_cited(&self, m: usize, position: usize) -> Result<(&Formula, &Formula, Vec<&Formula>), String> {
+        match self.lines[m].citations.get(position) {
+            Some(&Citation::Range(i, j)) => self.subproof_at(m, i, j),
+            _ => Err(format!("{} cere o subdemonstrație citată ca interval i-j", self.lines[m].rule.name())),
+        }
+    }
+
+    // Verifică linia cu indicele `m`; mesajul explică de ce regula nu se aplică
+    fn check_line(&self, m: usize) -> Result<(), String> {
+        let line = &self.lines[m];
+        let f = &line.formula;
+        match line.rule {
+            FitchRule::Premise => {
+                if !line.scopes.is_empty() || self.lines[..m].iter().any(|l| l.rule != FitchRule::Premise) {
+                    return Err("premisele trebuie să fie primele linii ale demonstrației principale".to_string());
+                }
+                Ok(())
+            },
+            FitchRule::Hypothesis => Ok(()),
+            FitchRule::Reiteration => {
+                let a = self.lines_cited(m, 1)?[0];
+                if a == f { Ok(()) } else { Err(format!("reiterarea trebuie să copieze formula {}", a)) }
+            },
+            FitchRule::AndIntro => {
+                let cited = self.lines_cited(m, 2)?;
+                match f {
+                    Formula::And(l, r) if **l == *cited[0] && **r == *cited[1] => Ok(()),
+                    _ => Err(format!("din {} și {} se obține {}", cited[0], cited[1], Formula::and(cited[0].clone(), cited[1].clone()))),
+                }
+            },
+            FitchRule::AndElim => {
+                let a = self.lines_cited(m, 1)?[0];
+                match a {
+                    Formula::And(l, r) if **l == *f || **r == *f => Ok(()),
+                    Formula::And(l, r) => Err(format!("din {} se obține doar {} sau {}", a, l, r)),
+                    _ => Err(format!("linia citată trebuie să fie o conjuncție, dar este {}", a)),
+                }
+            },
+            FitchRule::OrIntro => {
+                let a = self.lines_cited(m, 1)?[0];
+                match f {
+                    Formula::Or(l, r) if **l == *a || **r == *a => Ok(()),
+                    Formula::Or(_, _) => Err(format!("niciun termen al disjuncției nu este {}", a)),
+                    _ => Err("∨I produce o disjuncție".to_string()),
+                }
+            },
+            FitchRule::OrElim => {
+                if line.citations.len() != 3 {
+                    return Err("∨E cere o disjuncție și două subdemonstrații (ex: ∨E 1, 2-4, 5-7)".to_string());
+                }
+                let disjunction = match line.citations[0] {
+                    Citation::Line(k) => self.line_at(m, k)?,
+                    Citation::Range(_, _) => return Err("primul argument al ∨E este linia disjuncției".to_string()),
+                };
+                let Formula::Or(a, b) = disjunction else {
+                    return Err(format!("linia citată trebuie să fie o disjuncție, dar este {}", disjunction));
+                };
+                let (h1, c1, _) = self.subproof_cited(m, 1)?;
+                let (h2, c2, _) = self.subproof_cited(m, 2)?;
+                if !((h1 == &**a && h2 == &**b) || (h1 == &**b && h2 == &**a)) {
+                    return Err(format!("subdemonstrațiile trebuie să pornească de la {} și de la {}", a, b));
+                }
+                if c1 != f || c2 != f {
+                    return Err(format!("ambele subdemonstrații trebuie să se încheie cu {}", f));
+                }
+                Ok(())
+            },
+            FitchRule::ImpliesIntro => {
+                let (hypothesis, last, _) = self.subproof_cited(m, 0)?;
+                let expected = Formula::implies(hypothesis.clone(), last.clone());
+                if *f == expected { Ok(()) } else { Err(format!("subdemonstrația justifică {}", expected)) }
+            },
+            FitchRule::ImpliesElim => {
+                let cited = self.lines_cited(m, 2)?;
+                let ok = |imp: &Formula, ant: &Formula| matches!(imp, Formula::Implies(l, r) if **l == *ant && **r == *f);
+                if ok(cited[0], cited[1]) || ok(cited[1], cited[0]) {
+                    return Ok(());
+                }
+                match (cited[0], cited[1]) {
+                    (Formula::Implies(l, r), other) | (other, Formula::Implies(l, r)) if **l == *other => Err(format!("modus ponens produce {}", r)),
+                    (Formula::Implies(l, _), _) | (_, Formula::Implies(l, _)) => Err(format!("lipsește antecedentul {}", l)),
+                    _ => Err("una dintre liniile citate trebuie să fie o implicație".to_string()),
+                }
+            },
+            FitchRule::NotIntro | FitchRule::Raa => {
+                let (hypothesis, _, body) = self.subproof_cited(m, 0)?;
+                if !body.iter().any(|a| body.iter().any(|b| is_contradictory(a, b))) {
+                    return Err("subdemonstrația nu conține o contradicție (A și ¬A)".to_string());
+                }
+                let expected = if line.rule == FitchRule::NotIntro {
+                    Formula::not(hypothesis.clone())
+                } else {
+                    match hypothesis {
+                        Formula::Not(inner) => (**inner).clone(),
+                        _ => return Err("RAA cere o subdemonstrație care pornește de la o negație ¬A".to_string()),
+                    }
+                };
+                if *f == expected { Ok(()) } else { Err(format!("{} justifică {}", line.rule.name(), expected)) }
+            },
+            FitchRule::NotElim => {
+                let cited = self.lines_cited(m, 2)?;
+                if is_contradictory(cited[0], cited[1]) { Ok(()) } else { Err(format!("{} și {} nu sunt contradictorii", cited[0], cited[1])) }
+            },
+            FitchRule::DoubleNegationElim => {
+                let a = self.lines_cited(m, 1)?[0];
+                match a {
+                    Formula::Not(inner) => match &**inner {
+                        Formula::Not(x) if **x == *f => Ok(()),
+                        Formula::Not(x) => Err(format!("din {} se obține {}", a, x)),
+                        _ => Err(format!("linia citată trebuie să fie o dublă negație, dar este {}", a)),
+                    },
+                    _ => Err(format!("linia citată trebuie să fie o dublă negație, dar este {}", a)),
+                }
+            },
+        }
+    }
+
+    // Verifică toate liniile; prima greșeală oprește verificarea
+    pub fn check(&self) -> Result<(), FitchError> {
+        for m in 0..self.lines.len() {
+            self.check_line(m).map_err(|message| FitchError { line: self.lines[m].number, message })?;
+        }
+        let last = self.lines.last().expect("Demonstrație nevidă");
+        if !last.scopes.is_empty() {
+            return Err(FitchError { line: last.number, message: "demonstrația se termină într-o subdemonstrație deschisă".to_string() });
+        }
+        Ok(())
+    }
+
+    pub fn premises(&self) -> Vec<&Formula> {
+        self.lines.iter().filter(|l| l.rule == FitchRule::Premise).map(|l| &l.formula).collect()
+    }
+
+    pub fn conclusion(&self) -> &Formula {
+        &self.lines.last().expect("Demonstrație nevidă").formula
+    }
+
+    // Demonstrația, cu ✓ pe liniile corecte până la prima greșeală, apoi verdictul
+    pub fn print_check(&self) -> bool {
+        let result = self.check();
+        let failed = result.as_ref().err().map(|e| e.line);
+        for line in &self.lines {
+            let bars = "│ ".repeat(line.scopes.len() + 1);
+            let citations = line.citations.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ");
+            let body = format!("{}{}", bars, line.formula);
+            let text = format!("{:>3} {:<44} {}", line.number, body, format!("{} {}", line.rule.name(), citations).trim_end());
+            match failed {
+                Some(n) if n == line.number => { println!("{} {}", text.red().bold(), "✗".red().bold()); break; },
+                _ => println!("{} {}", text, "✓".green()),
+            }
+        }
+
+        match result {
+            Ok(()) => {
+                let premises = self.premises().iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", ");
+                let sequent = format!("{} ⊢ {}", premises, self.conclusion().to_string().yellow());
+                println!("\n{} {}", "Demonstrație corectă:".green().bold(), sequent.trim_start());
+                true
+            },
+            Err(e) => {
+                println!("\n{} {}", "Greșeală la".red().bold(), e);
+                false
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(text: &str) -> Result<(), FitchError> {
+        FitchProof::parse(text)?.check()
+    }
+
+    fn error(text: &str) -> FitchError {
+        check(text).expect_err("Demonstrația ar trebui respinsă")
+    }
+
+    const MODUS_TOLLENS: &str = "
+        1 | P -> Q      ; Prem
+        2 | !Q          ; Prem
+          |---
+        3 | | P         ; Ip
+        4 | | Q         ; ->E 1,3
+        5 | | !Q        ; R 2
+        6 | !P          ; !I 3-5
+    ";
+
+    #[test]
+    fn valid_proofs_are_accepted() {
+        assert_eq!(check(MODUS_TOLLENS), Ok(()));
+        let proof = FitchProof::parse(MODUS_TOLLENS).unwrap();
+        assert_eq!(proof.premises().len(), 2);
+        assert_eq!(proof.conclusion().to_string(), "¬P");
+
+        let commutativity = "
+            1 | P | Q       ; Prem
+            2 | | P         ; Ip
+            3 | | Q | P     ; |I 2
+            4 | | Q         ; Ip
+            5 | | Q | P     ; |I 4
+            6 | Q | P       ; |E 1, 2-3, 4-5
+        ";
+        assert_eq!(check(commutativity), Ok(()));
+
+        // Fără premise: demonstrația poate începe direct cu o ipoteză
+        let excluded_middle = "
+            1 | | !(P | !P)     ; Ip
+            2 | | | P           ; Ip
+            3 | | | P | !P      ; |I 2
+            4 | | | !(P | !P)   ; R 1
+            5 | | !P            ; !I 2-4
+            6 | | P | !P        ; |I 5
+            7 | P | !P          ; RAA 1-6
+        ";
+        assert_eq!(check(excluded_middle), Ok(()));
+    }
+
+    #[test]
+    fn citing_a_closed_box_is_rejected() {
+        let text = "
+            1 | P -> Q      ; Prem
+            2 | | P         ; Ip
+            3 | | Q         ; ->E 1,2
+            4 | P -> Q      ; ->I 2-3
+            5 | Q           ; R 3
+        ";
+        let e = error(text);
+        assert_eq!(e.line, 5);
+        assert!(e.message.contains("subdemonstrație închisă"), "{}", e.message);
+    }
+
+    #[test]
+    fn wrong_rule_applications_are_reported() {
+        let e = error("1 | P & Q ; Prem\n2 | R ; &E 1\n");
+        assert_eq!(e, FitchError { line: 2, message: "din (P ∧ Q) se obține doar P sau Q".to_string() });
+        let e = error("1 | P -> Q ; Prem\n2 | Q ; ->E 1\n");
+        assert!(e.message.contains("cere 2 linii"));
+        let e = error("1 | P ; Prem\n2 | | Q ; Ip\n");
+        assert!(e.message.contains("subdemonstrație deschisă"));
+        let e = error("1 | P ; Prem\n2 | | P -> Q ; Ip\n3 | P ; Prem\n");
+        assert!(e.message.contains("premisele trebuie"));
+    }
+
+    #[test]
+    fn malformed_files() {
+        let e = FitchProof::parse("1 | P ; Prem\n3 | P ; R 1\n").err().unwrap();
+        assert_eq!(e.line, 3);
+        let e = FitchProof::parse("1 | P & ; Prem\n").err().unwrap();
+        assert!(e.message.starts_with("formulă invalidă: Sintaxă invalidă"), "{}", e.message);
+        let e = FitchProof::parse("1 | P ; XYZ\n").err().unwrap();
+        assert!(e.message.contains("regulă necunoscută"));
+        assert!(FitchProof::parse("# doar un comentariu\n").is_err());
+    }
+
+    #[test]
+    fn trailing_tokens_are_rejected() {
+        // Textul rămas după formulă nu e ignorat: "P Q R" nu e citit ca P
+        for text in ["1 | P ; Prem\n2 | P Q R ; R 1\n", "1 | P & Q) ; Prem\n"] {
+            let e = FitchProof::parse(text).err().unwrap_or_else(|| panic!("{:?} ar trebui respins", text));
+            assert!(e.message.contains("în plus după formulă"), "{}", e.message);
+        }
+        let e = FitchProof::parse("1 | P ; Prem\n2 | P Q R ; R 1\n").err().unwrap();
+        assert_eq!(e.line, 2);
+    }
+
+    #[test]
+    fn syntax_errors_do_not_panic() {
+        assert!(try_parse_formula("(p & q").unwrap_err().contains("Așteptam RParen"));
+        assert!(try_parse_formula("p - q").unwrap_err().contains("așteptam '>'"));
+        assert!(try_parse_formula("p q").unwrap_err().contains("Var(\"q\") în plus"));
+        assert!(try_parse_formula("(p)) | q").is_err());
+        assert_eq!(try_parse_formula("p -> q"), Ok(Formula::implies(Formula::var("p"), Formula::var("q"))));
+    }
+}
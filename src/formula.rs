@@ -1,8 +1,14 @@
+use std::collections::HashMap;
 use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Clone, PartialEq, Eq, Hash, Serialize, Debug, Deserialize)]
+use crate::parser::try_parse_formula;
+
+// Atribuirea de valori de adevăr pentru variabile (nume -> valoare)
+pub type Valuation = HashMap<String, bool>;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Formula {
     Var(String),
     Not(Box<Formula>),
@@ -23,8 +29,24 @@ impl fmt::Display for Formula {
     }
 }
 
+// În JSON, formula e scrisă ca text (ex: "(p ∧ ¬q)"), nu ca arbore: adâncimea fișierului
+// nu crește odată cu adâncimea formulei
+impl Serialize for Formula {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Formula {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        try_parse_formula(&text).map_err(de::Error::custom)
+    }
+}
+
 impl Formula {
     pub fn var(n: &str) -> Formula { Formula::Var(n.to_string()) }
+    #[allow(clippy::should_implement_trait)]
     pub fn not(f: Formula) -> Formula { Formula::Not(Box::new(f)) }
     pub fn and(l: Formula, r: Formula) -> Formula { Formula::And(Box::new(l), Box::new(r)) }
     pub fn or(l: Formula, r: Formula) -> Formula { Formula::Or(Box::new(l), Box::new(r)) }
@@ -47,4 +69,35 @@ impl Formula {
             }
         }
     }
+
+    // Variabilele formulei, în ordinea primei apariții (de la stânga la dreapta)
+    pub fn variables(&self) -> Vec<String> {
+        let mut vars = Vec::new();
+        self.collect_variables(&mut vars);
+        vars
+    }
+
+    fn collect_variables(&self, vars: &mut Vec<String>) {
+        match self {
+            Formula::Var(n) => {
+                if !vars.contains(n) { vars.push(n.clone()); }
+            },
+            Formula::Not(inner) => inner.collect_variables(vars),
+            Formula::And(l, r) | Formula::Or(l, r) | Formula::Implies(l, r) => {
+                l.collect_variables(vars);
+                r.collect_variables(vars);
+            }
+        }
+    }
+
+    // Valoarea de adevăr a formulei; variabilele lipsă din atribuire sunt considerate false.
+    pub fn eval(&self, valuation: &Valuation) -> bool {
+        match self {
+            Formula::Var(n) => valuation.get(n).copied().unwrap_or(false),
+            Formula::Not(inner) => !inner.eval(valuation),
+            Formula::And(l, r) => l.eval(valuation) && r.eval(valuation),
+            Formula::Or(l, r) => l.eval(valuation) || r.eval(valuation),
+            Formula::Implies(l, r) => !l.eval(valuation) || r.eval(valuation),
+        }
+    }
 }
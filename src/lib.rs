@@ -2,4 +2,13 @@ pub mod formula;
 pub mod parser;
 pub mod tableau;
 pub mod minimizer;
+pub mod bdd;
+pub mod post;
+pub mod truth_table;
+pub mod cnf;
+pub mod normal_form;
+pub mod sat;
+pub mod resolution;
+pub mod sequent;
+pub mod fitch;
 pub mod ui;
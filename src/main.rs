@@ -1,22 +1,37 @@
-use crate::formula::Formula;
 use colored::*;
+use std::env;
 use std::io::{self};
+use std::time::Duration;
 
-mod formula;
-mod tableau;
-mod parser;
-mod minimizer;
-mod ui;
+mod cli;
 
-use tableau::{build_tableau, print_tree};
-use parser::parse_formula;
-use minimizer::BooleanFunction;
+use lc::bdd::{Bdd, VarOrder};
+use lc::formula::Formula;
+use lc::tableau::{build_tableau_with_limits, manual, print_tree};
+use lc::tableau::stats::{Limits, Verdict};
+use lc::parser::parse_formula;
+use lc::minimizer::{espresso, input, BooleanFunction};
+use lc::minimizer::multi::MultiOutputFunction;
+use lc::minimizer::pla::Pla;
+use lc::normal_form::{self, NormalForm};
+use lc::truth_table::TruthTable;
+use lc::ui;
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        cli::run(&args);
+        return;
+    }
+
     loop {
         println!("\n{}", "=== LOGIC TOOLKIT ===".on_blue().white().bold());
         println!("1. Solver Logică (Tabele Semantice)");
         println!("2. Minimizare Circuite (Quine-McCluskey)");
+        println!("3. Diagrame de Decizie Binară (BDD)");
+        println!("4. Tabel de Adevăr");
+        println!("5. Forme Normale (FNN, FNC, FND, Tseitin)");
+        println!("6. Tablou Manual (exercițiu)");
         println!("0. Ieșire");
         
         let choice = ui::read_line("\nAlege modul > ");
@@ -24,6 +39,10 @@ fn main() {
         match choice.as_str() {
             "1" => {run_tableau(); return},
             "2" => run_minimizer(),
+            "3" => run_bdd(),
+            "4" => run_truth_table(),
+            "5" => run_normal_forms(),
+            "6" => run_manual_tableau(),
             "0" => break,
             _ => println!("Opțiune invalidă!"),
         }
@@ -49,6 +68,7 @@ fn run_tableau() {
     };
 
     let parsed_formula = parse_formula(clean_input);
+    let limits = read_limits();
 
     println!("{}", "--------------------------------------------------".dimmed());
 
@@ -65,26 +85,29 @@ fn run_tableau() {
         println!("Formula de lucru (Negată): {}", negated_formula.to_string().yellow().bold());
         println!("\n{}", "Se construiește arborele...".cyan().bold());
         
-        let root = build_tableau(vec![negated_formula]);
+        let (root, stats) = build_tableau_with_limits(vec![negated_formula], &limits);
         print_tree(&root, "".to_string(), true);
+        println!();
+        stats.print();
 
 
         println!("\n{}", "=== ETAPA 3: CONCLUZIE FINALĂ ===".purple().bold());
         println!("{}", "--------------------------------------------------".dimmed());
         
-        match root.status {
-            tableau::node::NodeStatus::Closed => {
+        match stats.verdict() {
+            Verdict::Unsatisfiable => {
                 println!("{}: {}","REZULTAT".blue().bold(), " TAUTOLOGIE DEMONSTRATĂ ".on_green().white().bold());
                 println!("{}: Toate ramurile negației s-au închis (au generat contradicții).", "Analiză:".blue().bold());
                 println!("{}:   Nu există nicio situație în care formula să fie Falsă.", "Logica".blue().bold());
                 println!("{}:  Formula {} este validă logic.", "Verdict".blue().bold(), clean_input.bold());
             },
-            _ => {
+            Verdict::Satisfiable => {
                 println!("{}: {}","REZULTAT".blue().bold(), " NU ESTE TAUTOLOGIE ".on_red().white().bold());
                 println!("{}  Arborele negației a rămas cu ramuri deschise.", "Analiză:".blue().bold());
                 println!("{}:   Am găsit cel puțin un scenariu (Contra-exemplu) unde negația e Adevărată.", "Logica".blue().bold());
                 println!("{}:  Formula originală poate fi falsă.", "Verdict".blue().bold());
-            }
+            },
+            Verdict::Unknown => print_partial_tree(),
         }
     } else {
         // --- MOD SATISFIABILITATE ---
@@ -92,17 +115,23 @@ fn run_tableau() {
         println!("\n{}", "=== VERIFICARE SATISFIABILITATE ===".purple().bold());
         println!("{}: Căutăm o combinație de valori (Model) pentru care formula e Adevărată.", "Scop".blue().bold());
         
-        let root = build_tableau(vec![parsed_formula]);
+        let (root, stats) = build_tableau_with_limits(vec![parsed_formula], &limits);
         println!("\n{}:", "Arborele generat".cyan().bold());
         print_tree(&root, "".to_string(), true);
+        println!();
+        stats.print();
 
         println!("\n{}", "=== CONCLUZIE ===".purple().bold());
-        if let tableau::node::NodeStatus::Closed = root.status {
-            println!("{}: {}", "REZULTAT".blue().bold(), " CONTRADICȚIE (UNSAT) ".on_red().white().bold());
-            println!("Formula nu poate fi adevărată niciodată.");
-        } else {
-            println!("{}: {}", "REZULTAT".blue().bold(), " SATISFIABILĂ (SAT) ".on_green().white().bold());
-            println!("Există ramuri deschise. Orice ramură deschisă reprezintă o soluție posibilă.");
+        match stats.verdict() {
+            Verdict::Unsatisfiable => {
+                println!("{}: {}", "REZULTAT".blue().bold(), " CONTRADICȚIE (UNSAT) ".on_red().white().bold());
+                println!("Formula nu poate fi adevărată niciodată.");
+            },
+            Verdict::Satisfiable => {
+                println!("{}: {}", "REZULTAT".blue().bold(), " SATISFIABILĂ (SAT) ".on_green().white().bold());
+                println!("Există ramuri deschise. Orice ramură deschisă reprezintă o soluție posibilă.");
+            },
+            Verdict::Unknown => print_partial_tree(),
         }
     }
     let mut dummy = String::new();
@@ -110,25 +139,229 @@ fn run_tableau() {
 
 }
 
+// Limitele construcției; Enter păstrează valoarea implicită, 0 înseamnă fără limită
+fn read_limits() -> Limits {
+    let defaults = Limits::interactive();
+    println!("{}", "Limite (Enter = implicit, 0 = fără limită):".dimmed());
+    let count = |text: &str| text.parse::<usize>().ok();
+    let seconds = |text: &str| text.parse::<f64>().ok().and_then(|s| Duration::try_from_secs_f64(s).ok());
+    Limits {
+        max_nodes: read_limit("Noduri", defaults.max_nodes, |n| n.to_string(), count),
+        max_depth: read_limit("Adâncime", defaults.max_depth, |d| d.to_string(), count),
+        timeout: read_limit("Secunde", defaults.timeout, |t| t.as_secs_f64().to_string(), seconds),
+    }
+}
+
+// O limită citită de la tastatură: 0 înseamnă fără limită, iar o valoare invalidă
+// (ex: 2.5 noduri, inf sau -1 secunde) e raportată și înlocuită cu cea implicită
+fn read_limit<T: Copy + Default + PartialEq>(prompt: &str, default: Option<T>, show: impl Fn(T) -> String, parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let shown = default.map_or("fără".to_string(), &show);
+    let text = ui::read_line(&format!("  {} [{}] > ", prompt, shown));
+    if text.is_empty() { return default; }
+    match parse(&text) {
+        Some(v) if v == T::default() => None,
+        Some(v) => Some(v),
+        None => {
+            println!("{}", format!("Valoare invalidă: '{}', folosesc {}.", text, shown).yellow());
+            default
+        },
+    }
+}
+
+fn print_partial_tree() {
+    println!("{}: {}", "REZULTAT".blue().bold(), " NECUNOSCUT ".on_yellow().black().bold());
+    println!("Construcția s-a oprit la o limită: arborele de mai sus e parțial (ramurile ❓ nu au fost explorate).");
+    println!("Nicio ramură explorată nu a rămas deschisă; mărește limitele pentru un verdict.");
+}
+
+fn run_manual_tableau() {
+    println!("{}", "--- TABLOU MANUAL ---".purple().bold());
+    println!("Exemplu valid: {} sau {}", "P & (Q -> P)".yellow(), "prove (P -> P)".yellow());
+    let input = ui::read_line("Logic > ");
+    if input.is_empty() { return; }
+
+    // Pentru a demonstra o formulă, tabloul pornește de la negația ei
+    let formula = match input.trim().strip_prefix("prove ") {
+        Some(rest) => Formula::not(parse_formula(rest)),
+        None => parse_formula(&input),
+    };
+    println!("Formula de lucru: {}", formula.to_string().yellow().bold());
+    manual::run(vec![formula]);
+}
+
 fn run_minimizer() {
     println!("{}", "--- MOD CIRCUITE ---".cyan());
-    
-    // Folosim funcțiile din ui.rs
-    let vars = ui::read_variables();
-    let num_vars = vars.len();
-    let minterms = ui::read_minterms(num_vars);
+    println!("1. Variabile + mintermi");
+    println!("2. Formulă logică (ex: (A & B) | (A & !B & C))");
+    println!("3. Variabile + maxtermi (zerourile funcției)");
+    println!("4. Notație cu indici (ex: f(a,b,c,d) = Σm(1,3,7,11,15) + d(0,2))");
+    println!("5. Fișier cu tabel de adevăr (CSV sau PLA)");
+    println!("6. Funcție cu mai multe ieșiri (PLA sau câte o linie Σm pe ieșire)");
 
-    if minterms.is_empty() {
-        println!("Niciun minterm introdus. Funcția e 0.");
+    let choice = ui::read_line("Sursa funcției > ");
+    if choice == "6" {
+        run_multi_output();
         return;
     }
+    let func = match choice.as_str() {
+        "2" => {
+            let input = ui::read_line("Formula > ");
+            if input.is_empty() { return; }
+            let formula = parse_formula(&input);
+            println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+            BooleanFunction::from_formula(&formula)
+        },
+        "3" => {
+            let vars = ui::read_variables();
+            let num_vars = vars.len();
+            let maxterms = ui::read_maxterms(num_vars);
+            let dont_cares = ui::read_dont_cares(num_vars);
+            BooleanFunction::from_maxterms(vars, maxterms, dont_cares)
+        },
+        "4" => match input::parse_index_notation(&ui::read_line("Funcția > ")) {
+            Ok(func) => func,
+            Err(e) => {
+                println!("{}", format!("Eroare: {}", e).red());
+                return;
+            }
+        },
+        "5" => {
+            // Fișierul rămâne sub formă de cuburi: Espresso nu are nevoie de tabelul complet
+            let pla = match input::read_cubes(&ui::read_line("Calea fișierului > ")) {
+                Ok(pla) => pla,
+                Err(e) => {
+                    println!("{}", format!("Eroare: {}", e).red());
+                    return;
+                }
+            };
+            let form = read_form();
+            if form == "4" {
+                espresso::print_result(&pla, &espresso::minimize_pla(&pla), 0);
+            } else {
+                let func = pla.to_function(0);
+                func.print_veitch();
+                simplify_with_form(&func, &form);
+            }
+            println!("\nApasă Enter pt a continua...");
+            let _ = io::stdin().read_line(&mut String::new());
+            return;
+        },
+        _ => {
+            // Folosim funcțiile din ui.rs
+            let vars = ui::read_variables();
+            let num_vars = vars.len();
+            let minterms = ui::read_minterms(num_vars);
+            let dont_cares = ui::read_dont_cares(num_vars);
+            BooleanFunction::with_dont_cares(vars, minterms, dont_cares)
+        }
+    };
+
+    let form = read_form();
+    func.print_veitch(); // Arată diagrama (1-5 variabile)
+    simplify_with_form(&func, &form);
+
+    println!("\nApasă Enter pt a continua...");
+    let _ = io::stdin().read_line(&mut String::new());
+}
+
+fn read_form() -> String {
+    println!("\nForma dorită: 1. SOP (sumă de produse)  2. POS (produs de sume)  3. Comparație SOP vs POS");
+    println!("              4. SOP euristic (Espresso, pentru funcții mari)  5. SOP pas cu pas (tabele QM)");
+    println!("              6. Circuite doar cu NAND / doar cu NOR");
+    println!("              7. Hazarduri statice-1 și acoperire fără hazard");
+    println!("              8. ESOP (XOR de produse)  9. Factorizare multi-nivel");
+    ui::read_line("Forma > ")
+}
+
+// Rulăm metoda aleasă pe obiectul creat
+fn simplify_with_form(func: &BooleanFunction, form: &str) {
+    match form {
+        "2" => func.simplify_pos(),
+        "3" => func.compare_forms(),
+        "4" => func.simplify_heuristic(),
+        "5" => func.simplify_verbose(),
+        "6" => func.print_circuits(),
+        "7" => func.analyze_hazards(),
+        "8" => func.simplify_esop(),
+        "9" => func.simplify_factored(),
+        _ => func.simplify(), // Face magia Quine-McCluskey
+    }
+}
+
+fn run_truth_table() {
+    println!("{}", "--- MOD TABEL DE ADEVĂR ---".purple().bold());
+    let input = ui::read_line("Formula > ");
+    if input.is_empty() { return; }
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}\n", formula.to_string().yellow().bold());
+    match TruthTable::new(&formula) {
+        Ok(table) => table.print(),
+        Err(e) => println!("{}", format!("Eroare: {}", e).red()),
+    }
+
+    println!("\nApasă Enter pt a continua...");
+    let _ = io::stdin().read_line(&mut String::new());
+}
+
+fn run_normal_forms() {
+    println!("{}", "--- MOD FORME NORMALE ---".purple().bold());
+    let input = ui::read_line("Formula > ");
+    if input.is_empty() { return; }
+    let formula = parse_formula(&input);
+    println!("Formula Parsată: {}", formula.to_string().yellow().bold());
+
+    let steps = ui::read_line("Afișez pașii de rescriere? (d/n) > ").to_lowercase().starts_with('d');
+    normal_form::print_forms(&formula, &[NormalForm::Nnf, NormalForm::Cnf, NormalForm::Dnf], steps, true);
+
+    println!("\nApasă Enter pt a continua...");
+    let _ = io::stdin().read_line(&mut String::new());
+}
+
+fn run_bdd() {
+    println!("{}", "--- MOD BDD (DIAGRAME DE DECIZIE BINARĂ) ---".purple().bold());
+    let input = ui::read_line("Formula > ");
+    if input.is_empty() { return; }
+    let formula = parse_formula(&input);
+
+    println!("Ordinea variabilelor: Enter = ordinea apariției, 'alpha', 'best' sau o listă (ex: a,c,b)");
+    let order = ui::read_line("Ordine > ");
+    let order = if order.is_empty() { VarOrder::Appearance } else { VarOrder::parse(&order) };
+
+    let (bdd, root) = Bdd::from_formula(&formula, &order);
+    bdd.print(root);
+    bdd.print_summary(root);
+
+    println!("\nApasă Enter pt a continua...");
+    let _ = io::stdin().read_line(&mut String::new());
+}
+
+fn run_multi_output() {
+    println!("Introdu calea unui fișier PLA sau câte o ieșire pe linie (ex: s(a,b) = Σm(1,2)).");
+    println!("Scrie '{}' când ai terminat.", "gata".bold());
+
+    let mut named = Vec::new();
+    let mut func = None;
+    loop {
+        let line = ui::read_line("Ieșire > ");
+        if line.is_empty() || line.eq_ignore_ascii_case("gata") { break; }
+
+        if line.to_lowercase().ends_with(".pla") {
+            func = Some(Pla::read(&line).and_then(|pla| MultiOutputFunction::from_pla(&pla)));
+            break;
+        }
+        let name = line.split('(').next().unwrap_or("").trim().to_string();
+        match input::parse_index_notation(&line) {
+            Ok(f) => named.push((name, f)),
+            Err(e) => println!("{}", format!("Eroare: {}", e).red()),
+        }
+    }
+
+    match func.unwrap_or_else(|| MultiOutputFunction::from_functions(named)) {
+        Ok(multi) if !multi.outputs.is_empty() => multi.simplify(),
+        Ok(_) => println!("Nicio ieșire introdusă."),
+        Err(e) => println!("{}", format!("Eroare: {}", e).red()),
+    }
 
-    // Creăm obiectul și rulăm metodele
-    let func = BooleanFunction::new(vars, minterms);
-    
-    func.print_veitch(); // Arată diagrama (dacă sunt 3 vars)
-    func.simplify();     // Face magia Quine-McCluskey
-    
     println!("\nApasă Enter pt a continua...");
     let _ = io::stdin().read_line(&mut String::new());
 }
@@ -0,0 +1,187 @@
+use crate::post::Anf;
+
+use super::term::Term;
+
+// Peste acest număr de variabile, polaritatea formei Reed-Muller e căutată greedy, nu exhaustiv
+const EXHAUSTIVE_POLARITY_VARS: usize = 8;
+
+fn cost(cover: &[Vec<i8>]) -> (usize, usize) {
+    (cover.len(), cover.iter().map(|c| c.iter().filter(|&&b| b != -1).count()).sum())
+}
+
+// Forma Reed-Muller cu polaritate fixă: ANF-ul lui f(x ⊕ p), cu variabilele din p negate
+fn fixed_polarity(table: &[bool], n: usize, polarity: usize) -> Vec<Vec<i8>> {
+    let shifted: Vec<bool> = (0..table.len()).map(|x| table[x ^ polarity]).collect();
+    Anf::from_table(vec![String::new(); n], &shifted).monomials.into_iter()
+        .map(|m| (0..n).map(|i| {
+            let bit = 1 << (n - 1 - i);
+            if m & bit == 0 { -1 } else if polarity & bit == 0 { 1 } else { 0 }
+        }).collect())
+        .collect()
+}
+
+fn best_polarity(table: &[bool], n: usize) -> Vec<Vec<i8>> {
+    if n <= EXHAUSTIVE_POLARITY_VARS {
+        return (0..1usize << n)
+            .map(|p| fixed_polarity(table, n, p))
+            .min_by_key(|c| cost(c))
+            .unwrap_or_default();
+    }
+
+    // Greedy: inversăm câte o variabilă cât timp forma scade
+    let mut polarity = 0;
+    let mut best = fixed_polarity(table, n, polarity);
+    loop {
+        let candidate = (0..n)
+            .map(|i| (polarity ^ (1 << i), fixed_polarity(table, n, polarity ^ (1 << i))))
+            .min_by_key(|(_, c)| cost(c));
+        match candidate {
+            Some((p, c)) if cost(&c) < cost(&best) => {
+                polarity = p;
+                best = c;
+            },
+            _ => return best,
+        }
+    }
+}
+
+// Cuburile la distanță 0 se anulează (A ⊕ A = 0), cele la distanță 1 se unesc într-unul singur:
+// xA ⊕ !xA = A, xA ⊕ A = !xA, !xA ⊕ A = xA
+fn merge_cubes(mut cover: Vec<Vec<i8>>) -> Vec<Vec<i8>> {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let mut i = 0;
+        while i < cover.len() {
+            let mut j = i + 1;
+            let mut removed = false;
+            while j < cover.len() {
+                let diff: Vec<usize> = (0..cover[i].len()).filter(|&v| cover[i][v] != cover[j][v]).collect();
+                match diff.len() {
+                    0 => {
+                        cover.swap_remove(j);
+                        cover.swap_remove(i);
+                        removed = true;
+                        break;
+                    },
+                    1 => {
+                        // Valoarea care lipsește din perechea {0, 1, -}
+                        let v = diff[0];
+                        cover[i][v] = -cover[i][v] - cover[j][v];
+                        cover.swap_remove(j);
+                        changed = true;
+                        // Cubul nou poate fi unit și cu cele deja verificate
+                        j = i + 1;
+                    },
+                    _ => j += 1,
+                }
+            }
+            if removed {
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    cover
+}
+
+fn esop_of_table(table: &[bool], n: usize) -> Vec<Vec<i8>> {
+    // Două puncte de plecare: cea mai bună formă Reed-Muller și mintermii (o sumă disjunctă)
+    let from_rm = merge_cubes(best_polarity(table, n));
+    let minterms: Vec<Vec<i8>> = (0..table.len()).filter(|&x| table[x]).map(|x| Term::from_index(x, n).bits).collect();
+    let from_minterms = merge_cubes(minterms);
+    if cost(&from_minterms) < cost(&from_rm) { from_minterms } else { from_rm }
+}
+
+// Acoperire ESOP (XOR de produse), euristic. Don't care-urile sunt încercate
+// toate pe 0 și toate pe 1; se păstrează varianta mai ieftină.
+pub fn minimize(num_vars: usize, ones: &[usize], dont_cares: &[usize]) -> Vec<Term> {
+    let mut table = vec![false; 1 << num_vars];
+    for &m in ones {
+        table[m] = true;
+    }
+    let mut best = esop_of_table(&table, num_vars);
+
+    if !dont_cares.is_empty() {
+        for &d in dont_cares {
+            table[d] = true;
+        }
+        let with_dc = esop_of_table(&table, num_vars);
+        if cost(&with_dc) < cost(&best) {
+            best = with_dc;
+        }
+    }
+
+    best.sort();
+    best.into_iter().map(|bits| Term { bits, used: false }).collect()
+}
+
+// Valoarea acoperirii ESOP pe linia `idx` (paritatea cuburilor care o conțin)
+pub fn eval(cover: &[Term], idx: usize) -> bool {
+    cover.iter().filter(|t| t.covers(idx)).count() % 2 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn parity_is_a_xor_of_literals() {
+        // a ⊕ b ⊕ c: 4 mintermi fără nicio unire în SOP, 3 literali în ESOP
+        let ones = [1, 2, 4, 7];
+        let cover = minimize(3, &ones, &[]);
+        assert_eq!(cover.len(), 3);
+        assert!(cover.iter().all(|t| t.literal_count() == 1));
+        for idx in 0..8 {
+            assert_eq!(eval(&cover, idx), ones.contains(&idx));
+        }
+    }
+
+    #[test]
+    fn merging_cancels_and_joins_cubes() {
+        // 1- ⊕ 1- = 0 și 10 ⊕ 11 = 1-
+        assert!(merge_cubes(vec![vec![1, -1], vec![1, -1]]).is_empty());
+        assert_eq!(merge_cubes(vec![vec![1, 0], vec![1, 1]]), vec![vec![1, -1]]);
+        assert_eq!(merge_cubes(vec![vec![1, 0], vec![-1, 0]]), vec![vec![0, 0]]);
+    }
+
+    #[test]
+    fn random_functions_are_preserved() {
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let n = 2 + (next(&mut state) % 4) as usize;
+            let mut ones = Vec::new();
+            let mut dont_cares = Vec::new();
+            for idx in 0..1usize << n {
+                match next(&mut state) % 5 {
+                    0 | 1 => ones.push(idx),
+                    2 => dont_cares.push(idx),
+                    _ => {},
+                }
+            }
+            let cover = minimize(n, &ones, &dont_cares);
+            assert!(cover.len() <= ones.len() + dont_cares.len());
+            for idx in 0..1usize << n {
+                if !dont_cares.contains(&idx) {
+                    assert_eq!(eval(&cover, idx), ones.contains(&idx), "n = {}, ones = {:?}, linia {}", n, ones, idx);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert!(minimize(2, &[], &[]).is_empty());
+        let one = minimize(2, &[0, 1, 2, 3], &[]);
+        assert_eq!(one.len(), 1);
+        assert_eq!(one[0].literal_count(), 0);
+    }
+}
@@ -0,0 +1,439 @@
+use std::collections::BTreeMap;
+use colored::*;
+
+use super::pla::Pla;
+use super::term::Term;
+use super::veitch::MAX_MAP_VARS;
+
+// Un cub peste n variabile: 0, 1 sau -1 (liber), ca în `Term::bits`
+type Cube = Vec<i8>;
+
+fn is_universe(c: &[i8]) -> bool {
+    c.iter().all(|&b| b == -1)
+}
+
+fn literal_count(c: &[i8]) -> usize {
+    c.iter().filter(|&&b| b != -1).count()
+}
+
+fn intersects(a: &[i8], b: &[i8]) -> bool {
+    a.iter().zip(b).all(|(&x, &y)| x == -1 || y == -1 || x == y)
+}
+
+// `a` conține cubul `b`
+fn contains(a: &[i8], b: &[i8]) -> bool {
+    a.iter().zip(b).all(|(&x, &y)| x == -1 || x == y)
+}
+
+// Cofactorul acoperirii față de cubul `d`: cuburile care îl intersectează, cu literalii lui `d` eliminați
+fn cofactor(cover: &[Cube], d: &[i8]) -> Vec<Cube> {
+    cover.iter()
+        .filter(|c| intersects(c, d))
+        .map(|c| c.iter().zip(d).map(|(&x, &y)| if y == -1 { x } else { -1 }).collect())
+        .collect()
+}
+
+fn cofactor_var(cover: &[Cube], var: usize, value: i8) -> Vec<Cube> {
+    cover.iter()
+        .filter(|c| c[var] == -1 || c[var] == value)
+        .map(|c| {
+            let mut c = c.clone();
+            c[var] = -1;
+            c
+        })
+        .collect()
+}
+
+// Variabila cu cei mai mulți literali (preferând variabilele binate) pentru descompunerea Shannon
+fn splitting_var(cover: &[Cube], n: usize) -> Option<usize> {
+    (0..n)
+        .map(|v| {
+            let zeros = cover.iter().filter(|c| c[v] == 0).count();
+            let ones = cover.iter().filter(|c| c[v] == 1).count();
+            (v, zeros.min(ones), zeros + ones)
+        })
+        .filter(|&(_, _, total)| total > 0)
+        .max_by_key(|&(v, binate, total)| (binate, total, std::cmp::Reverse(v)))
+        .map(|(v, _, _)| v)
+}
+
+// Verifică dacă acoperirea e tautologie (acoperă tot spațiul), cu reducere unată
+fn tautology(cover: &[Cube], n: usize) -> bool {
+    if cover.iter().any(|c| is_universe(c)) { return true; }
+    if cover.is_empty() { return false; }
+
+    let mut reduced: Vec<Cube> = cover.to_vec();
+    let mut binate = false;
+    for v in 0..n {
+        let has0 = reduced.iter().any(|c| c[v] == 0);
+        let has1 = reduced.iter().any(|c| c[v] == 1);
+        if has0 && has1 {
+            binate = true;
+        } else if has0 || has1 {
+            // Într-o variabilă unată, cuburile care depind de ea nu pot ajuta la tautologie
+            reduced.retain(|c| c[v] == -1);
+            if reduced.iter().any(|c| is_universe(c)) { return true; }
+            if reduced.is_empty() { return false; }
+        }
+    }
+    if !binate { return false; }
+
+    match splitting_var(&reduced, n) {
+        Some(v) => tautology(&cofactor_var(&reduced, v, 0), n) && tautology(&cofactor_var(&reduced, v, 1), n),
+        None => false,
+    }
+}
+
+fn covers_cube(cover: &[Cube], c: &[i8], n: usize) -> bool {
+    tautology(&cofactor(cover, c), n)
+}
+
+// Complementul unei acoperiri prin descompunere Shannon recursivă
+fn complement(cover: &[Cube], n: usize) -> Vec<Cube> {
+    if cover.is_empty() { return vec![vec![-1; n]]; }
+    if cover.iter().any(|c| is_universe(c)) { return Vec::new(); }
+    if cover.len() == 1 {
+        // De Morgan: fiecare literal negat devine un cub
+        return cover[0].iter().enumerate()
+            .filter(|(_, &b)| b != -1)
+            .map(|(i, &b)| {
+                let mut c = vec![-1; n];
+                c[i] = 1 - b;
+                c
+            })
+            .collect();
+    }
+
+    let v = splitting_var(cover, n).expect("Acoperirea are cel puțin un literal");
+    let low = complement(&cofactor_var(cover, v, 0), n);
+    let mut high = complement(&cofactor_var(cover, v, 1), n);
+    let mut result = Vec::new();
+
+    // Cuburile identice din ambele jumătăți nu depind de v
+    for c in low {
+        if let Some(pos) = high.iter().position(|d| *d == c) {
+            high.remove(pos);
+            result.push(c);
+        } else {
+            let mut c = c;
+            c[v] = 0;
+            result.push(c);
+        }
+    }
+    for mut c in high {
+        c[v] = 1;
+        result.push(c);
+    }
+    single_cube_containment(result)
+}
+
+// Cel mai mic cub care conține complementul acoperirii (None dacă acoperirea e tautologie),
+// calculat recursiv fără a construi complementul
+fn complement_supercube(cover: &[Cube], n: usize) -> Option<Cube> {
+    if cover.is_empty() { return Some(vec![-1; n]); }
+    if cover.iter().any(|c| is_universe(c)) { return None; }
+    if cover.len() == 1 {
+        let literals: Vec<usize> = (0..n).filter(|&i| cover[0][i] != -1).collect();
+        let mut c = vec![-1; n];
+        if literals.len() == 1 {
+            c[literals[0]] = 1 - cover[0][literals[0]];
+        }
+        return Some(c);
+    }
+
+    let v = splitting_var(cover, n)?;
+    let low = cofactor_var(cover, v, 0);
+    let high = cofactor_var(cover, v, 1);
+
+    let a = complement_supercube(&low, n);
+    // Dacă o jumătate dă deja tot spațiul, cealaltă contează doar prin faptul că există
+    if a.as_ref().is_some_and(|c| is_universe(c)) {
+        let mut a = a.unwrap();
+        if tautology(&high, n) { a[v] = 0; }
+        return Some(a);
+    }
+    let b = complement_supercube(&high, n);
+
+    match (a, b) {
+        (None, None) => None,
+        (Some(mut a), None) => { a[v] = 0; Some(a) },
+        (None, Some(mut b)) => { b[v] = 1; Some(b) },
+        (Some(a), Some(b)) => Some(a.iter().zip(&b).map(|(&x, &y)| if x == y { x } else { -1 }).collect()),
+    }
+}
+
+fn single_cube_containment(mut cover: Vec<Cube>) -> Vec<Cube> {
+    cover.sort_by_key(|c| literal_count(c));
+    cover.dedup();
+    let mut kept: Vec<Cube> = Vec::new();
+    for c in cover {
+        if !kept.iter().any(|k| contains(k, &c)) {
+            kept.push(c);
+        }
+    }
+    kept
+}
+
+// Mulțimea față de care se verifică un cub mărit: OFF explicit sau ON ∪ DC original
+enum Bound<'a> {
+    Off(&'a [Cube]),
+    Care(&'a [Cube]),
+}
+
+impl Bound<'_> {
+    fn allows(&self, c: &[i8], n: usize) -> bool {
+        match self {
+            Bound::Off(off) => !off.iter().any(|r| intersects(r, c)),
+            Bound::Care(care) => covers_cube(care, c, n),
+        }
+    }
+}
+
+// EXPAND: fiecare cub e mărit (literali eliminați) cât timp rămâne în ON ∪ DC
+fn expand(cover: Vec<Cube>, bound: &Bound, n: usize) -> Vec<Cube> {
+    let mut cubes = cover;
+    cubes.sort_by_key(|c| literal_count(c));
+    let mut result: Vec<Cube> = Vec::new();
+
+    for cube in &cubes {
+        if result.iter().any(|r| contains(r, cube)) { continue; }
+
+        // Întâi literalii în care cubul diferă de cele mai multe alte cuburi
+        let mut order: Vec<usize> = (0..cube.len()).filter(|&v| cube[v] != -1).collect();
+        order.sort_by_key(|&v| std::cmp::Reverse(cubes.iter().filter(|c| c[v] != cube[v]).count()));
+
+        let mut c = cube.clone();
+        for v in order {
+            let saved = c[v];
+            c[v] = -1;
+            if !bound.allows(&c, n) {
+                c[v] = saved;
+            }
+        }
+        result.retain(|r| !contains(&c, r));
+        result.push(c);
+    }
+    result
+}
+
+// IRREDUNDANT: elimină cuburile acoperite de restul acoperirii plus don't care-uri
+fn irredundant(cover: Vec<Cube>, dc: &[Cube], n: usize) -> Vec<Cube> {
+    let mut cubes = cover;
+    cubes.sort_by_key(|c| std::cmp::Reverse(literal_count(c)));
+
+    let mut i = 0;
+    while i < cubes.len() {
+        let others: Vec<Cube> = cubes.iter().enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, c)| c.clone())
+            .chain(dc.iter().cloned())
+            .collect();
+        if covers_cube(&others, &cubes[i], n) {
+            cubes.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    cubes
+}
+
+// REDUCE: fiecare cub e micșorat la cel mai mic cub care acoperă partea pe care doar el o acoperă
+fn reduce(cover: Vec<Cube>, dc: &[Cube], n: usize) -> Vec<Cube> {
+    let mut cubes = cover;
+    cubes.sort_by_key(|c| literal_count(c));
+
+    let mut i = 0;
+    while i < cubes.len() {
+        let others: Vec<Cube> = cubes.iter().enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, c)| c.clone())
+            .chain(dc.iter().cloned())
+            .collect();
+        let supercube = match complement_supercube(&cofactor(&others, &cubes[i]), n) {
+            Some(c) => c,
+            None => {
+                cubes.remove(i);
+                continue;
+            }
+        };
+        for (x, s) in cubes[i].iter_mut().zip(supercube) {
+            if *x == -1 { *x = s; }
+        }
+        i += 1;
+    }
+    cubes
+}
+
+fn cost(cover: &[Cube]) -> (usize, usize) {
+    (cover.len(), cover.iter().map(|c| literal_count(c)).sum())
+}
+
+// Minimizare euristică în stilul Espresso: EXPAND, IRREDUNDANT, apoi bucle REDUCE-EXPAND-IRREDUNDANT
+// cât timp costul scade. Fără OFF explicit, expandarea e verificată prin tautologie pe ON ∪ DC,
+// așa că complementul funcției nu e calculat niciodată.
+pub fn minimize(on: &[Vec<i8>], dc: &[Vec<i8>], off: Option<&[Vec<i8>]>, num_vars: usize) -> Vec<Term> {
+    if on.is_empty() { return Vec::new(); }
+
+    let care: Vec<Cube> = on.iter().chain(dc).cloned().collect();
+    let bound = match off {
+        Some(off) => Bound::Off(off),
+        None => Bound::Care(&care),
+    };
+
+    let mut cover = irredundant(expand(on.to_vec(), &bound, num_vars), dc, num_vars);
+    loop {
+        let reduced = reduce(cover.clone(), dc, num_vars);
+        let candidate = irredundant(expand(reduced, &bound, num_vars), dc, num_vars);
+        if cost(&candidate) < cost(&cover) {
+            cover = candidate;
+        } else {
+            break;
+        }
+    }
+
+    cover.sort();
+    cover.into_iter().map(|bits| Term { bits, used: false }).collect()
+}
+
+// Acoperirea Espresso a unei ieșiri, calculată direct pe cuburile PLA-ului
+pub fn minimize_output(pla: &Pla, output: usize) -> Vec<Term> {
+    let n = pla.input_names.len();
+    let (on, mut dc, off) = pla.output_cubes(output);
+    if let Some(off) = &off {
+        // La tipurile cu zerouri explicite, tot ce nu e nici ON, nici OFF devine don't care
+        let specified: Vec<Cube> = on.iter().chain(off).chain(&dc).cloned().collect();
+        dc.extend(complement(&specified, n));
+    }
+    minimize(&on, &dc, off.as_deref(), n)
+}
+
+// Minimizează fiecare ieșire a unui PLA și construiește PLA-ul rezultat;
+// cuburile identice din ieșiri diferite sunt scrise o singură dată.
+pub fn minimize_pla(pla: &Pla) -> Pla {
+    let m = pla.output_names.len();
+    let mut rows: BTreeMap<Vec<i8>, Vec<char>> = BTreeMap::new();
+
+    for o in 0..m {
+        for t in minimize_output(pla, o) {
+            rows.entry(t.bits).or_insert_with(|| vec!['0'; m])[o] = '1';
+        }
+    }
+
+    Pla {
+        input_names: pla.input_names.clone(),
+        output_names: pla.output_names.clone(),
+        cubes: rows.into_iter().collect(),
+        kind: "f".to_string(),
+    }
+}
+
+// Rezultatul minimizării: acoperirea fiecărei ieșiri și, pentru funcțiile mici,
+// diagrama Veitch a ieșirii `shown` (doar atunci e construit tabelul de adevăr complet).
+pub fn print_result(pla: &Pla, result: &Pla, shown: usize) {
+    println!("\n=== REZULTAT SIMPLIFICARE (ESPRESSO) ===");
+    for (o, name) in result.output_names.iter().enumerate() {
+        let cover = result.output_cover(o);
+        let mut terms: Vec<String> = cover.iter().map(|t| t.to_string(&result.input_names)).collect();
+        terms.sort();
+        let text = if terms.is_empty() { "0 (FALSE)".to_string() } else { terms.join(" v ") };
+        println!("{} = {}", name.bold(), text.cyan().bold());
+    }
+    println!("{}", format!("{} cuburi în PLA-ul rezultat (inițial {})", result.cubes.len(), pla.cubes.len()).dimmed());
+
+    if pla.input_names.len() <= MAX_MAP_VARS {
+        let cover = result.output_cover(shown);
+        let labels: Vec<String> = cover.iter().map(|t| t.to_string(&pla.input_names)).collect();
+        pla.to_function(shown).print_veitch_groups(&cover, &labels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimizer::qm;
+
+    // Generator determinist (xorshift), ca testele să nu depindă de o bibliotecă externă
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn cubes(indices: &[usize], n: usize) -> Vec<Cube> {
+        indices.iter().map(|&i| Term::from_index(i, n).bits).collect()
+    }
+
+    #[test]
+    fn matches_quine_mccluskey_on_small_functions() {
+        let mut state = 0x9e3779b97f4a7c15;
+        let mut extra_terms = 0;
+        for _ in 0..300 {
+            let n = 2 + (next(&mut state) % 4) as usize;
+            let (mut ones, mut dcs) = (Vec::new(), Vec::new());
+            for idx in 0..(1usize << n) {
+                match next(&mut state) % 5 {
+                    0 | 1 => ones.push(idx),
+                    2 => dcs.push(idx),
+                    _ => {},
+                }
+            }
+            let cover = minimize(&cubes(&ones, n), &cubes(&dcs, n), None, n);
+            for idx in 0..(1usize << n) {
+                let covered = cover.iter().any(|t| t.covers(idx));
+                if ones.contains(&idx) {
+                    assert!(covered, "{:?} / {:?}: mintermul {} lipsește", ones, dcs, idx);
+                } else if !dcs.contains(&idx) {
+                    assert!(!covered, "{:?} / {:?}: zeroul {} e acoperit", ones, dcs, idx);
+                }
+            }
+            let exact = qm::minimal_cover(&qm::prime_implicants(n, &ones, &dcs), &ones);
+            assert!(cover.len() >= exact.len());
+            extra_terms += cover.len() - exact.len();
+        }
+        // Euristica poate rata optimul, dar rar
+        assert!(extra_terms < 30, "{} termeni în plus față de QM", extra_terms);
+    }
+
+    #[test]
+    fn explicit_off_set_bounds_expansion() {
+        // Cu OFF = {000}, liniile nespecificate pot fi folosite: un singur literal (a, b sau c) ajunge
+        let on = cubes(&[7], 3);
+        let off = cubes(&[0], 3);
+        let cover = minimize(&on, &[], Some(&off), 3);
+        assert!(cover.iter().all(|t| !t.covers(0)));
+        assert!(cover.iter().any(|t| t.covers(7)));
+        assert_eq!(cover.iter().map(|t| t.literal_count()).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn wide_plas_are_minimized_on_cubes() {
+        // 30 de intrări: tabelul de adevăr ar avea 2^30 linii, cuburile doar câteva
+        let mut state = 0x2545f4914f6cdd1d;
+        let n = 30;
+        let on: Vec<Cube> = (0..12)
+            .map(|_| (0..n).map(|_| match next(&mut state) % 4 { 0 => 0, 1 => 1, _ => -1 }).collect())
+            .collect();
+        let pla = Pla {
+            input_names: (0..n).map(|i| format!("x{}", i)).collect(),
+            output_names: vec!["f".to_string()],
+            cubes: on.iter().map(|c| (c.clone(), vec!['1'])).collect(),
+            kind: "f".to_string(),
+        };
+        let cover: Vec<Cube> = minimize_output(&pla, 0).into_iter().map(|t| t.bits).collect();
+        assert!(cover.len() <= on.len());
+        assert!(on.iter().all(|c| covers_cube(&cover, c, n)));
+        assert!(cover.iter().all(|c| covers_cube(&on, c, n)));
+    }
+
+    #[test]
+    fn minimized_pla_keeps_every_output() {
+        let pla = Pla::parse(".i 3\n.o 2\n.ilb a b c\n.ob f g\n000 11\n001 10\n011 11\n111 01\n.e\n").unwrap();
+        let result = minimize_pla(&pla);
+        assert_eq!(result.output_names, vec!["f", "g"]);
+        let reread = Pla::parse(&result.to_text()).unwrap();
+        for o in 0..2 {
+            assert_eq!(reread.to_function(o).minterm_indices(), pla.to_function(o).minterm_indices());
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use super::term::Term;
+
+// O ieșire minimizată: acoperirea SOP (cuburi de unu) sau POS (cuburi de zero)
+pub struct CoverOutput {
+    pub name: String,
+    pub cover: Vec<Term>,
+    pub pos: bool,
+}
+
+const VERILOG_KEYWORDS: &[&str] = &[
+    "and", "or", "not", "xor", "xnor", "nand", "nor", "buf", "input", "output", "inout",
+    "wire", "reg", "module", "endmodule", "assign", "begin", "end", "if", "else", "case",
+];
+
+// Numele ca identificator valid: caracterele nepermise devin '_', cuvintele rezervate primesc '_'
+pub fn identifier(name: &str) -> String {
+    let mut id: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if id.is_empty() || id.starts_with(|c: char| c.is_ascii_digit()) {
+        id.insert(0, '_');
+    }
+    if VERILOG_KEYWORDS.contains(&id.as_str()) {
+        id.push('_');
+    }
+    id
+}
+
+// Numele modulului derivat din numele fișierului (ex: "adder.v" → "adder")
+pub fn module_name(path: &str) -> String {
+    let stem = Path::new(path).file_stem().and_then(|s| s.to_str()).unwrap_or("circuit");
+    identifier(stem)
+}
+
+fn verilog_expr(inputs: &[String], out: &CoverOutput) -> String {
+    // Acoperire goală: SOP = 0, POS = 1; un cub fără literali dă constanta opusă
+    if out.cover.is_empty() {
+        return if out.pos { "1'b1" } else { "1'b0" }.to_string();
+    }
+    if out.cover.iter().any(|t| t.literal_count() == 0) {
+        return if out.pos { "1'b0" } else { "1'b1" }.to_string();
+    }
+
+    let (and, or) = if out.pos { (" | ", " & ") } else { (" & ", " | ") };
+    let mut groups: Vec<String> = out.cover.iter()
+        .map(|t| {
+            let literals: Vec<String> = t.bits.iter().enumerate()
+                .filter(|(_, &b)| b != -1)
+                .map(|(i, &b)| {
+                    // În POS, bitul 1 al cubului de zero înseamnă literalul negat
+                    let negated = if out.pos { b == 1 } else { b == 0 };
+                    if negated { format!("~{}", inputs[i]) } else { inputs[i].clone() }
+                })
+                .collect();
+            if literals.len() == 1 || out.cover.len() == 1 {
+                literals.join(and)
+            } else {
+                format!("({})", literals.join(and))
+            }
+        })
+        .collect();
+    groups.sort();
+    groups.join(or)
+}
+
+// Modul Verilog sintetizabil, cu câte un `assign` pentru fiecare ieșire
+pub fn to_verilog(module: &str, input_names: &[String], outputs: &[CoverOutput]) -> String {
+    let inputs: Vec<String> = input_names.iter().map(|n| identifier(n)).collect();
+    let ports: Vec<String> = inputs.iter().map(|n| format!("    input  wire {}", n))
+        .chain(outputs.iter().map(|o| format!("    output wire {}", identifier(&o.name))))
+        .collect();
+
+    let mut v = String::from("// Generat de lc din acoperirea minimă\n");
+    v.push_str(&format!("module {} (\n{}\n);\n", identifier(module), ports.join(",\n")));
+    for out in outputs {
+        v.push_str(&format!("    assign {} = {};\n", identifier(&out.name), verilog_expr(&inputs, out)));
+    }
+    v.push_str("endmodule\n");
+    v
+}
+
+// Model BLIF: un bloc `.names` pe ieșire, cu rânduri ON (SOP) sau OFF (POS)
+pub fn to_blif(model: &str, input_names: &[String], outputs: &[CoverOutput]) -> String {
+    let inputs: Vec<String> = input_names.iter().map(|n| identifier(n)).collect();
+    let mut b = String::from("# Generat de lc din acoperirea minimă\n");
+    b.push_str(&format!(".model {}\n", identifier(model)));
+    b.push_str(&format!(".inputs {}\n", inputs.join(" ")));
+    b.push_str(&format!(".outputs {}\n", outputs.iter().map(|o| identifier(&o.name)).collect::<Vec<_>>().join(" ")));
+
+    for out in outputs {
+        let name = identifier(&out.name);
+        // Acoperire goală: SOP = 0 (niciun rând), POS = 1 (constanta 1)
+        if out.cover.is_empty() {
+            b.push_str(&format!(".names {}\n", name));
+            if out.pos { b.push_str("1\n"); }
+            continue;
+        }
+        // Doar variabilele care apar în acoperire
+        let support: Vec<usize> = (0..inputs.len())
+            .filter(|&i| out.cover.iter().any(|t| t.bits[i] != -1))
+            .collect();
+        let header: Vec<&str> = support.iter().map(|&i| inputs[i].as_str()).chain([name.as_str()]).collect();
+        b.push_str(&format!(".names {}\n", header.join(" ")));
+
+        let value = if out.pos { '0' } else { '1' };
+        for t in &out.cover {
+            let cube: String = support.iter().map(|&i| match t.bits[i] { 0 => '0', 1 => '1', _ => '-' }).collect();
+            if cube.is_empty() {
+                b.push_str(&format!("{}\n", value));
+            } else {
+                b.push_str(&format!("{} {}\n", cube, value));
+            }
+        }
+    }
+    b.push_str(".end\n");
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(bits: &[i8]) -> Term {
+        Term { bits: bits.to_vec(), used: false }
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn identifiers_are_sanitized() {
+        assert_eq!(identifier("c-out"), "c_out");
+        assert_eq!(identifier("1st"), "_1st");
+        assert_eq!(identifier("and"), "and_");
+        assert_eq!(module_name("out/full adder.v"), "full_adder");
+    }
+
+    #[test]
+    fn verilog_sop_and_pos() {
+        let sop = CoverOutput { name: "f".to_string(), cover: vec![term(&[1, 1, -1]), term(&[0, -1, 1])], pos: false };
+        let pos = CoverOutput { name: "g".to_string(), cover: vec![term(&[0, 0, -1])], pos: true };
+        let v = to_verilog("m", &names(&["a", "b", "c"]), &[sop, pos]);
+        assert!(v.contains("module m ("));
+        assert!(v.contains("assign f = (a & b) | (~a & c);"));
+        assert!(v.contains("assign g = a | b;"));
+        assert!(v.ends_with("endmodule\n"));
+    }
+
+    #[test]
+    fn blif_lists_only_the_support() {
+        let out = CoverOutput { name: "f".to_string(), cover: vec![term(&[1, -1, 0])], pos: false };
+        let b = to_blif("m", &names(&["a", "b", "c"]), &[out]);
+        assert!(b.contains(".names a c f\n10 1\n"));
+        let zero = CoverOutput { name: "z".to_string(), cover: Vec::new(), pos: false };
+        assert!(to_blif("m", &names(&["a"]), &[zero]).contains(".names z\n.end"));
+    }
+}
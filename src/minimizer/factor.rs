@@ -0,0 +1,270 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::formula::Formula;
+use super::term::Term;
+
+// Un literal e codificat ca 2 * variabilă + (1 dacă e negat); un cub e mulțimea literalilor lui
+type Cube = BTreeSet<usize>;
+type Sop = Vec<Cube>;
+
+// Peste acest număr de kerneluri căutarea se oprește (acoperirile mari au foarte multe)
+const KERNEL_LIMIT: usize = 256;
+
+fn literal_count(f: &[Cube]) -> usize {
+    f.iter().map(|c| c.len()).sum()
+}
+
+fn normalize(mut f: Sop) -> Sop {
+    f.sort();
+    f.dedup();
+    f
+}
+
+// Cubul comun tuturor cuburilor (intersecția lor)
+fn common_cube(f: &[Cube]) -> Cube {
+    let mut iter = f.iter();
+    let first = iter.next().cloned().unwrap_or_default();
+    iter.fold(first, |acc, c| acc.intersection(c).copied().collect())
+}
+
+fn divide_by_cube(f: &[Cube], c: &Cube) -> Sop {
+    normalize(f.iter().filter(|x| c.is_subset(x)).map(|x| x.difference(c).copied().collect()).collect())
+}
+
+// Împărțirea algebrică (slabă): F = Q·D + R
+fn divide(f: &[Cube], d: &[Cube]) -> (Sop, Sop) {
+    let mut quotient: Option<BTreeSet<Cube>> = None;
+    for dc in d {
+        let q: BTreeSet<Cube> = divide_by_cube(f, dc).into_iter().collect();
+        quotient = Some(match quotient {
+            None => q,
+            Some(prev) => prev.intersection(&q).cloned().collect(),
+        });
+    }
+    let quotient: Sop = quotient.unwrap_or_default().into_iter().collect();
+
+    let product: BTreeSet<Cube> = quotient.iter()
+        .flat_map(|q| d.iter().map(move |dc| q.union(dc).copied().collect()))
+        .collect();
+    let remainder = f.iter().filter(|c| !product.contains(*c)).cloned().collect();
+    (quotient, remainder)
+}
+
+// Kernelurile lui F: câturile F / c fără cub comun (c = co-kernel), calculate recursiv
+fn kernels(f: &[Cube], min_literal: usize, result: &mut Vec<Sop>) {
+    if result.len() >= KERNEL_LIMIT { return; }
+
+    let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+    for c in f {
+        for &l in c {
+            *counts.entry(l).or_insert(0) += 1;
+        }
+    }
+    for (&l, &count) in &counts {
+        if l < min_literal || count < 2 { continue; }
+        let with_l: Sop = f.iter().filter(|c| c.contains(&l)).cloned().collect();
+        let co = common_cube(&with_l);
+        // Kernelul a fost deja găsit pornind de la un literal mai mic
+        if co.iter().any(|&x| x < l) { continue; }
+        kernels(&divide_by_cube(f, &co), l + 1, result);
+    }
+    if common_cube(f).is_empty() && f.len() > 1 && !result.iter().any(|k| k.as_slice() == f) {
+        result.push(f.to_vec());
+    }
+}
+
+fn literal(var_names: &[String], l: usize) -> Formula {
+    let v = Formula::var(&var_names[l / 2]);
+    if l % 2 == 1 { Formula::not(v) } else { v }
+}
+
+fn conjunction(parts: Vec<Formula>) -> Formula {
+    parts.into_iter().reduce(Formula::and).expect("Produs fără factori")
+}
+
+fn disjunction(parts: Vec<Formula>) -> Formula {
+    parts.into_iter().reduce(Formula::or).expect("Sumă fără termeni")
+}
+
+fn cube_formula(var_names: &[String], c: &Cube) -> Formula {
+    conjunction(c.iter().map(|&l| literal(var_names, l)).collect())
+}
+
+// Factorizare algebrică recursivă: scoate cubul comun, apoi împarte la kernelul cu cel mai mare
+// câștig de literali; dacă niciun kernel nu ajută, scoate în factor literalul cel mai frecvent.
+fn factor_sop(var_names: &[String], f: &[Cube]) -> Formula {
+    if f.len() == 1 {
+        return cube_formula(var_names, &f[0]);
+    }
+
+    let common = common_cube(f);
+    if !common.is_empty() {
+        let rest = factor_sop(var_names, &divide_by_cube(f, &common));
+        return Formula::and(cube_formula(var_names, &common), rest);
+    }
+
+    let mut found = Vec::new();
+    kernels(f, 0, &mut found);
+    let before = literal_count(f);
+    let best = found.iter()
+        .filter(|k| k.len() < f.len())
+        .filter_map(|k| {
+            let (q, r) = divide(f, k);
+            if q.is_empty() { return None; }
+            let saved = before as isize - (literal_count(&q) + literal_count(k) + literal_count(&r)) as isize;
+            Some((saved, k.clone(), q, r))
+        })
+        .max_by_key(|(saved, k, _, _)| (*saved, std::cmp::Reverse(k.len())));
+
+    if let Some((saved, k, q, r)) = best {
+        if saved > 0 {
+            let product = Formula::and(factor_sop(var_names, &q), factor_sop(var_names, &k));
+            return if r.is_empty() { product } else { Formula::or(product, factor_sop(var_names, &r)) };
+        }
+    }
+
+    // Literalul care apare în cele mai multe cuburi
+    let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+    for c in f {
+        for &l in c {
+            *counts.entry(l).or_insert(0) += 1;
+        }
+    }
+    let frequent = counts.iter().filter(|(_, &n)| n >= 2).max_by_key(|(&l, &n)| (n, std::cmp::Reverse(l)));
+    if let Some((&l, _)) = frequent {
+        let lit: Cube = [l].into_iter().collect();
+        let (q, r) = divide(f, &[lit]);
+        let product = Formula::and(literal(var_names, l), factor_sop(var_names, &q));
+        return if r.is_empty() { product } else { Formula::or(product, factor_sop(var_names, &r)) };
+    }
+
+    disjunction(f.iter().map(|c| cube_formula(var_names, c)).collect())
+}
+
+// Forma factorizată a unei acoperiri SOP; None pentru funcțiile constante (acoperire goală sau cu cubul universal)
+pub fn factor(var_names: &[String], cover: &[Term]) -> Option<Formula> {
+    if cover.is_empty() || cover.iter().any(|t| t.literal_count() == 0) {
+        return None;
+    }
+    let sop: Sop = normalize(cover.iter()
+        .map(|t| t.bits.iter().enumerate()
+            .filter(|(_, &b)| b != -1)
+            .map(|(i, &b)| 2 * i + if b == 0 { 1 } else { 0 })
+            .collect())
+        .collect());
+    Some(factor_sop(var_names, &sop))
+}
+
+// Numărul de apariții ale variabilelor în formulă
+pub fn formula_literals(f: &Formula) -> usize {
+    match f {
+        Formula::Var(_) => 1,
+        Formula::Not(inner) => formula_literals(inner),
+        Formula::And(l, r) | Formula::Or(l, r) | Formula::Implies(l, r) => formula_literals(l) + formula_literals(r),
+    }
+}
+
+// Scrierea compactă, în notația minimizatorului (ex: "ab(!c v d) v e")
+pub fn to_compact(f: &Formula) -> String {
+    match f {
+        Formula::Var(n) => n.clone(),
+        Formula::Not(inner) => match &**inner {
+            Formula::Var(n) => format!("!{}", n),
+            other => format!("!({})", to_compact(other)),
+        },
+        Formula::And(l, r) => {
+            let side = |x: &Formula| match x {
+                Formula::Or(_, _) | Formula::Implies(_, _) => format!("({})", to_compact(x)),
+                _ => to_compact(x),
+            };
+            format!("{}{}", side(l), side(r))
+        },
+        Formula::Or(l, r) => format!("{} v {}", to_compact(l), to_compact(r)),
+        Formula::Implies(l, r) => format!("({} -> {})", to_compact(l), to_compact(r)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::Valuation;
+
+    fn names(n: usize) -> Vec<String> {
+        ["a", "b", "c", "d", "e"][..n].iter().map(|s| s.to_string()).collect()
+    }
+
+    fn terms(cubes: &[&str]) -> Vec<Term> {
+        cubes.iter().map(|c| Term {
+            bits: c.chars().map(|ch| match ch { '0' => 0, '1' => 1, _ => -1 }).collect(),
+            used: false,
+        }).collect()
+    }
+
+    // Formula factorizată are aceeași valoare ca acoperirea pe toate liniile
+    fn assert_equivalent(var_names: &[String], cover: &[Term], formula: &Formula) {
+        let n = var_names.len();
+        for idx in 0..1usize << n {
+            let valuation: Valuation = var_names.iter().enumerate()
+                .map(|(i, v)| (v.clone(), (idx >> (n - 1 - i)) & 1 == 1))
+                .collect();
+            assert_eq!(formula.eval(&valuation), cover.iter().any(|t| t.covers(idx)), "linia {}", idx);
+        }
+    }
+
+    #[test]
+    fn product_of_sums_is_recovered() {
+        // ac v ad v bc v bd = (a v b)(c v d)
+        let var_names = names(4);
+        let cover = terms(&["1-1-", "1--1", "-11-", "-1-1"]);
+        let formula = factor(&var_names, &cover).unwrap();
+        assert_eq!(formula_literals(&formula), 4);
+        assert_equivalent(&var_names, &cover, &formula);
+    }
+
+    #[test]
+    fn common_cube_is_extracted() {
+        // ab!c v abd = ab(!c v d)
+        let var_names = names(4);
+        let cover = terms(&["110-", "11-1"]);
+        let formula = factor(&var_names, &cover).unwrap();
+        assert_eq!(to_compact(&formula), "ab(!c v d)");
+        assert_equivalent(&var_names, &cover, &formula);
+    }
+
+    #[test]
+    fn kernels_of_a_textbook_example() {
+        // F = ace v bce v de v g: kernelurile sunt a v b, ac v bc v d și F însăși
+        let cube = |ls: &[usize]| ls.iter().copied().collect::<Cube>();
+        let (a, b, c, d, e, g) = (0, 2, 4, 6, 8, 10);
+        let f = normalize(vec![cube(&[a, c, e]), cube(&[b, c, e]), cube(&[d, e]), cube(&[g])]);
+        let mut found = Vec::new();
+        kernels(&f, 0, &mut found);
+        assert!(found.contains(&normalize(vec![cube(&[a]), cube(&[b])])));
+        assert!(found.contains(&normalize(vec![cube(&[a, c]), cube(&[b, c]), cube(&[d])])));
+        assert!(found.contains(&f));
+        assert_eq!(found.len(), 3);
+    }
+
+    #[test]
+    fn constants_are_not_factored() {
+        assert!(factor(&names(2), &[]).is_none());
+        assert!(factor(&names(2), &terms(&["--"])).is_none());
+    }
+
+    #[test]
+    fn factored_forms_never_grow() {
+        let funcs: [&[&str]; 3] = [
+            &["11--", "1-1-", "-111", "0--0"],
+            &["1-0-0", "-10-1", "011--", "1---1"],
+            &["111", "000"],
+        ];
+        for cubes in funcs {
+            let cover = terms(cubes);
+            let var_names = names(cover[0].bits.len());
+            let formula = factor(&var_names, &cover).unwrap();
+            let before: usize = cover.iter().map(|t| t.literal_count()).sum();
+            assert!(formula_literals(&formula) <= before);
+            assert_equivalent(&var_names, &cover, &formula);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::qm;
+use super::term::Term;
+
+// Hazard static-1: doi mintermi adiacenți (diferă printr-o singură variabilă)
+// care nu sunt acoperiți de același termen al acoperirii
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hazard {
+    pub from: usize,
+    pub to: usize,
+    pub var: usize,
+}
+
+impl Hazard {
+    // Cubul celor doi mintermi: variabila care se schimbă devine liberă
+    pub fn cube(&self, num_vars: usize) -> Term {
+        let mut t = Term::from_index(self.from, num_vars);
+        t.bits[self.var] = -1;
+        t
+    }
+}
+
+pub fn find_hazards(cover: &[Term], ones: &[usize], num_vars: usize) -> Vec<Hazard> {
+    let mut hazards = Vec::new();
+    for &m in ones {
+        for var in 0..num_vars {
+            let other = m ^ (1 << (num_vars - 1 - var));
+            // Fiecare pereche o singură dată
+            if other < m || !ones.contains(&other) { continue; }
+            if !cover.iter().any(|t| t.covers(m) && t.covers(other)) {
+                hazards.push(Hazard { from: m, to: other, var });
+            }
+        }
+    }
+    hazards
+}
+
+// Acoperire fără hazard static-1: la acoperirea dată se adaugă termeni de consens
+// (implicanți primi care conțin ambii mintermi), aleși greedy după câte hazarduri elimină
+pub fn hazard_free_cover(cover: &[Term], ones: &[usize], dont_cares: &[usize], num_vars: usize) -> Vec<Term> {
+    let primes = qm::prime_implicants(num_vars, ones, dont_cares);
+    let mut result = cover.to_vec();
+
+    loop {
+        let hazards = find_hazards(&result, ones, num_vars);
+        if hazards.is_empty() { break; }
+
+        let best = primes.iter()
+            .filter(|p| !result.contains(p))
+            .map(|p| {
+                let fixed = hazards.iter().filter(|h| p.covers(h.from) && p.covers(h.to)).count();
+                (p, fixed)
+            })
+            .filter(|&(_, fixed)| fixed > 0)
+            .max_by_key(|&(p, fixed)| (fixed, std::cmp::Reverse(p.literal_count())));
+
+        match best {
+            Some((p, _)) => result.push(p.clone()),
+            // Nu se poate întâmpla: perechea e un implicant, deci e inclusă într-un implicant prim
+            None => break,
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_hazard_is_fixed_by_the_consensus_term() {
+        // f = ab + !ac are hazard între 011 și 111; consensul bc îl elimină
+        let ones = [3, 6, 7, 1];
+        let cover = qm::minimal_cover(&qm::prime_implicants(3, &ones, &[]), &ones);
+        assert_eq!(cover.len(), 2);
+        let hazards = find_hazards(&cover, &ones, 3);
+        assert_eq!(hazards, vec![Hazard { from: 3, to: 7, var: 0 }]);
+        assert_eq!(hazards[0].cube(3).bit_string(), "-11");
+
+        let fixed = hazard_free_cover(&cover, &ones, &[], 3);
+        assert_eq!(fixed.len(), 3);
+        assert!(fixed.iter().any(|t| t.bit_string() == "-11"));
+        assert!(find_hazards(&fixed, &ones, 3).is_empty());
+    }
+
+    #[test]
+    fn hazard_free_cover_keeps_the_function() {
+        let ones = [0, 2, 5, 7, 8, 10, 13, 15];
+        let cover = qm::minimal_cover(&qm::prime_implicants(4, &ones, &[]), &ones);
+        let fixed = hazard_free_cover(&cover, &ones, &[], 4);
+        for idx in 0..16 {
+            assert_eq!(fixed.iter().any(|t| t.covers(idx)), ones.contains(&idx));
+        }
+        assert!(find_hazards(&fixed, &ones, 4).is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+use std::fs;
+
+use super::pla::Pla;
+use super::{bits_of, BooleanFunction};
+
+enum IndexList {
+    Minterms,
+    Maxterms,
+    DontCares,
+}
+
+fn classify(prefix: &str) -> Option<IndexList> {
+    let p: String = prefix.chars().filter(|c| !c.is_whitespace()).collect();
+    match p.as_str() {
+        "Σm" | "∑m" | "Sm" | "sm" | "sum" | "m" => Some(IndexList::Minterms),
+        "ΠM" | "∏M" | "PM" | "pM" | "prod" | "M" => Some(IndexList::Maxterms),
+        "d" | "dc" | "D" | "x" | "X" => Some(IndexList::DontCares),
+        _ => None,
+    }
+}
+
+// Notația cu indici: `f(a,b,c,d) = Σm(1,3,7,11,15) + d(0,2)` sau `f(a,b,c) = ΠM(0,4) · d(5)`.
+// Variantele ASCII `Sm`/`sum`/`m` și `PM`/`prod`/`M` sunt acceptate.
+pub fn parse_index_notation(input: &str) -> Result<BooleanFunction, String> {
+    let (lhs, rhs) = input.split_once('=')
+        .ok_or("Lipsește '=' (ex: f(a,b,c) = Σm(1,3,5))")?;
+
+    let open = lhs.find('(').ok_or("Lipsește lista de variabile (ex: f(a,b,c))")?;
+    let close = lhs.rfind(')').ok_or("Lipsește ')' după lista de variabile")?;
+    let names: Vec<String> = lhs[open + 1..close].split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if names.is_empty() {
+        return Err("Funcția trebuie să aibă cel puțin o variabilă.".to_string());
+    }
+    let n = names.len();
+
+    let mut minterms: Option<Vec<usize>> = None;
+    let mut maxterms: Option<Vec<usize>> = None;
+    let mut dont_cares = Vec::new();
+
+    let mut rest = rhs;
+    while let Some(open) = rest.find('(') {
+        let close = rest[open..].find(')').map(|c| open + c)
+            .ok_or("Paranteză neînchisă în lista de indici")?;
+        let prefix = rest[..open].trim_matches(|c: char| c.is_whitespace() || "+·*,&".contains(c));
+
+        let indices = rest[open + 1..close].split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.parse::<usize>() {
+                Ok(i) if i < (1usize << n) => Ok(i),
+                Ok(i) => Err(format!("Indicele {} depășește {} pentru {} variabile", i, (1usize << n) - 1, n)),
+                Err(_) => Err(format!("Indice invalid: '{}'", s)),
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+
+        match classify(prefix) {
+            Some(IndexList::Minterms) => minterms.get_or_insert_with(Vec::new).extend(indices),
+            Some(IndexList::Maxterms) => maxterms.get_or_insert_with(Vec::new).extend(indices),
+            Some(IndexList::DontCares) => dont_cares.extend(indices),
+            None => return Err(format!("Notație necunoscută: '{}' (folosește Σm, ΠM sau d)", prefix)),
+        }
+        rest = &rest[close + 1..];
+    }
+
+    // Un indice nu poate fi în același timp valoare fixă și don't care (de obicei e o greșeală de tastare)
+    for (list, kind) in [(&minterms, "minterm"), (&maxterms, "maxterm")] {
+        if let Some(&i) = list.iter().flatten().find(|i| dont_cares.contains(i)) {
+            return Err(format!("Indicele {} apare și ca {} și ca don't-care", i, kind));
+        }
+    }
+
+    let to_rows = |v: &[usize]| v.iter().map(|&i| bits_of(i, n)).collect::<Vec<_>>();
+    match (minterms, maxterms) {
+        (Some(ms), None) => Ok(BooleanFunction::with_dont_cares(names, to_rows(&ms), to_rows(&dont_cares))),
+        (None, Some(ms)) => Ok(BooleanFunction::from_maxterms(names, to_rows(&ms), to_rows(&dont_cares))),
+        (Some(_), Some(_)) => Err("Folosește fie Σm, fie ΠM, nu ambele.".to_string()),
+        (None, None) => Err("Lipsește lista Σm(...) sau ΠM(...).".to_string()),
+    }
+}
+
+pub fn read_csv(path: &str) -> Result<BooleanFunction, String> {
+    Ok(read_csv_cubes(path)?.to_function(0))
+}
+
+pub fn read_csv_cubes(path: &str) -> Result<Pla, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Nu pot citi fișierul {}: {}", path, e))?;
+    parse_csv_cubes(&text)
+}
+
+pub fn parse_csv(text: &str) -> Result<BooleanFunction, String> {
+    Ok(parse_csv_cubes(text)?.to_function(0))
+}
+
+// Tabel de adevăr CSV: antet cu variabilele și ieșirea (ultima coloană), apoi câte o linie
+// pe combinație. Ieșirea poate fi 0, 1 sau -/x (don't care); liniile lipsă sunt 0.
+//   a,b,c,f
+//   0,0,1,1
+// Rândurile cu '-' pe intrări rămân cuburi, ca într-un PLA cu o singură ieșire.
+pub fn parse_csv_cubes(text: &str) -> Result<Pla, String> {
+    let split = |line: &str| -> Vec<String> {
+        line.split([',', ';', '\t']).map(|s| s.trim().to_string()).collect()
+    };
+    let is_value = |s: &str| matches!(s, "0" | "1" | "-" | "x" | "X");
+
+    let mut lines = text.lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .peekable();
+
+    let first = split(lines.peek().ok_or("Fișierul CSV este gol.")?);
+    if first.len() < 2 {
+        return Err("Tabelul trebuie să aibă cel puțin o intrare și o ieșire.".to_string());
+    }
+    let n = first.len() - 1;
+    let (input_names, output_name) = if first.iter().all(|s| is_value(s)) {
+        ((0..n).map(|i| format!("x{}", i)).collect(), "f".to_string())
+    } else {
+        lines.next();
+        (first[..n].to_vec(), first[n].clone())
+    };
+
+    let mut cubes = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let cells = split(line);
+        if cells.len() != n + 1 || !cells.iter().all(|s| is_value(s)) {
+            return Err(format!("Rândul {} este invalid: '{}'", i + 1, line));
+        }
+        let cube: Vec<i8> = cells[..n].iter()
+            .map(|s| match s.as_str() { "0" => 0, "1" => 1, _ => -1 })
+            .collect();
+        let output = match cells[n].as_str() {
+            "1" => '1',
+            "0" => continue,
+            _ => '-',
+        };
+        cubes.push((cube, vec![output]));
+    }
+
+    Ok(Pla { input_names, output_names: vec![output_name], cubes, kind: "fd".to_string() })
+}
+
+// Citește un tabel de adevăr după extensie: `.pla` sau CSV, păstrând cuburile
+pub fn read_cubes(path: &str) -> Result<Pla, String> {
+    if path.to_lowercase().ends_with(".pla") {
+        Pla::read(path)
+    } else {
+        read_csv_cubes(path)
+    }
+}
+
+// Funcția primei ieșiri din `read_cubes`, cu tabelul de adevăr complet
+pub fn read_file(path: &str) -> Result<BooleanFunction, String> {
+    Ok(read_cubes(path)?.to_function(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_notation_with_dont_cares() {
+        let func = parse_index_notation("f(a,b,c,d) = Σm(1,3,7,11,15) + d(0,2,5)").unwrap();
+        assert_eq!(func.var_names, vec!["a", "b", "c", "d"]);
+        assert_eq!(func.minterm_indices(), vec![1, 3, 7, 11, 15]);
+        assert_eq!(func.dont_care_indices(), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn maxterm_notation_in_ascii() {
+        let func = parse_index_notation("f(a,b,c) = PM(0,4) * d(5)").unwrap();
+        assert_eq!(func.minterm_indices(), vec![1, 2, 3, 6, 7]);
+        assert_eq!(func.dont_care_indices(), vec![5]);
+    }
+
+    fn error(input: &str) -> String {
+        parse_index_notation(input).err().expect("Notația ar trebui respinsă")
+    }
+
+    #[test]
+    fn invalid_index_lists_are_rejected() {
+        assert!(error("f(a,b) = Σm(1,4)").contains("depășește"));
+        assert!(error("f(a,b) = Σm(1) + ΠM(0)").contains("nu ambele"));
+        assert_eq!(error("f(a,b,c) = Σm(1,3) + d(3,6)"), "Indicele 3 apare și ca minterm și ca don't-care");
+        assert_eq!(error("f(a,b) = ΠM(0) + d(0)"), "Indicele 0 apare și ca maxterm și ca don't-care");
+    }
+
+    #[test]
+    fn csv_rows_expand_dashes() {
+        let func = parse_csv("a,b,c,f\n1,-,1,1\n0,0,0,x\n").unwrap();
+        assert_eq!(func.minterm_indices(), vec![5, 7]);
+        assert_eq!(func.dont_care_indices(), vec![0]);
+        assert!(parse_csv("a,b,f\n1,2,1\n").is_err());
+    }
+
+    #[test]
+    fn csv_cubes_keep_dashes() {
+        let pla = parse_csv_cubes("a,b,c,f\n1,-,1,1\n0,0,0,x\n0,1,1,0\n").unwrap();
+        assert_eq!(pla.output_names, vec!["f"]);
+        assert_eq!(pla.cubes, vec![(vec![1, -1, 1], vec!['1']), (vec![0, 0, 0], vec!['-'])]);
+    }
+}
@@ -0,0 +1,412 @@
+pub mod term;
+pub mod qm;
+pub mod veitch;
+pub mod pla;
+pub mod input;
+pub mod multi;
+pub mod espresso;
+pub mod synthesis;
+pub mod export;
+pub mod hazard;
+pub mod esop;
+pub mod factor;
+
+use std::collections::{HashSet};
+use colored::*;
+
+use crate::formula::{Formula, Valuation};
+
+pub use term::Term;
+pub use synthesis::{GateKind, Netlist};
+
+// Indexul liniei din tabelul de adevăr (primul bit e cel mai semnificativ)
+pub fn index_of(bits: &[u8]) -> usize {
+    bits.iter().fold(0, |idx, &b| (idx << 1) | (b as usize))
+}
+
+pub fn bits_of(idx: usize, num_vars: usize) -> Vec<u8> {
+    (0..num_vars).map(|i| ((idx >> (num_vars - 1 - i)) & 1) as u8).collect()
+}
+
+// Costul unui circuit pe două niveluri. Negațiile intrărilor sunt considerate gratuite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cost {
+    pub gates: usize,
+    pub inputs: usize,
+}
+
+impl Cost {
+    pub fn of(cover: &[Term]) -> Cost {
+        let mut gates = 0;
+        let mut inputs = 0;
+        for t in cover {
+            let lits = t.literal_count();
+            if lits > 1 {
+                gates += 1;
+                inputs += lits;
+            }
+        }
+        if cover.len() > 1 {
+            gates += 1;
+            inputs += cover.len();
+        }
+        Cost { gates, inputs }
+    }
+}
+
+pub struct BooleanFunction {
+    pub var_names: Vec<String>,
+    pub minterms: Vec<Vec<u8>>,
+    pub dont_cares: Vec<Vec<u8>>,
+    minterm_indices: HashSet<usize>,
+    dont_care_indices: HashSet<usize>,
+}
+
+impl BooleanFunction {
+    pub fn new(names: Vec<String>, minterms: Vec<Vec<u8>>) -> Self {
+        BooleanFunction::with_dont_cares(names, minterms, Vec::new())
+    }
+
+    pub fn with_dont_cares(names: Vec<String>, minterms: Vec<Vec<u8>>, dont_cares: Vec<Vec<u8>>) -> Self {
+        let minterm_indices: HashSet<usize> = minterms.iter().map(|b| index_of(b)).collect();
+        let dont_care_indices = dont_cares.iter()
+            .map(|b| index_of(b))
+            .filter(|idx| !minterm_indices.contains(idx))
+            .collect();
+
+        BooleanFunction { var_names: names, minterms, dont_cares, minterm_indices, dont_care_indices }
+    }
+
+    // Funcția dată prin zerouri (maxtermi): mintermii sunt liniile rămase
+    pub fn from_maxterms(names: Vec<String>, maxterms: Vec<Vec<u8>>, dont_cares: Vec<Vec<u8>>) -> Self {
+        let n = names.len();
+        let zeros: HashSet<usize> = maxterms.iter().map(|b| index_of(b)).collect();
+        let dcs: HashSet<usize> = dont_cares.iter().map(|b| index_of(b)).collect();
+        let minterms = (0..(1usize << n))
+            .filter(|idx| !zeros.contains(idx) && !dcs.contains(idx))
+            .map(|idx| bits_of(idx, n))
+            .collect();
+
+        BooleanFunction::with_dont_cares(names, minterms, dont_cares)
+    }
+
+    // Construiește funcția din tabelul de adevăr al unei formule:
+    // variabilele sunt luate în ordinea apariției, iar mintermii sunt liniile unde formula e 1.
+    pub fn from_formula(formula: &Formula) -> Self {
+        let names = formula.variables();
+        let n = names.len();
+        let mut minterms = Vec::new();
+
+        for idx in 0..(1usize << n) {
+            let bits = bits_of(idx, n);
+            let valuation: Valuation = names.iter().cloned()
+                .zip(bits.iter().map(|&b| b == 1))
+                .collect();
+            if formula.eval(&valuation) {
+                minterms.push(bits);
+            }
+        }
+
+        BooleanFunction::new(names, minterms)
+    }
+
+    pub fn minterm_indices(&self) -> Vec<usize> {
+        let mut v: Vec<usize> = self.minterm_indices.iter().copied().collect();
+        v.sort();
+        v
+    }
+
+    pub fn dont_care_indices(&self) -> Vec<usize> {
+        let mut v: Vec<usize> = self.dont_care_indices.iter().copied().collect();
+        v.sort();
+        v
+    }
+
+    // Zerourile funcției (liniile care nu sunt nici mintermi, nici don't care)
+    pub fn maxterm_indices(&self) -> Vec<usize> {
+        (0..(1usize << self.var_names.len()))
+            .filter(|idx| !self.minterm_indices.contains(idx) && !self.dont_care_indices.contains(idx))
+            .collect()
+    }
+
+    // Acoperirea minimă sumă-de-produse
+    pub fn sop_cover(&self) -> Vec<Term> {
+        self.sop_cover_checked().0
+    }
+
+    // Acoperirea SOP și dacă e garantat minimă (vezi `qm::minimal_cover_checked`)
+    pub fn sop_cover_checked(&self) -> (Vec<Term>, bool) {
+        let ones = self.minterm_indices();
+        let primes = qm::prime_implicants(self.var_names.len(), &ones, &self.dont_care_indices());
+        qm::minimal_cover_checked(&primes, &ones)
+    }
+
+    // Acoperirea minimă produs-de-sume: cuburile acoperă zerourile, fiecare devine o clauză
+    pub fn pos_cover(&self) -> Vec<Term> {
+        self.pos_cover_checked().0
+    }
+
+    pub fn pos_cover_checked(&self) -> (Vec<Term>, bool) {
+        let zeros = self.maxterm_indices();
+        let primes = qm::prime_implicants(self.var_names.len(), &zeros, &self.dont_care_indices());
+        qm::minimal_cover_checked(&primes, &zeros)
+    }
+
+    // Acoperire euristică (Espresso), pentru funcții prea mari pentru QM + Petrick
+    pub fn espresso_cover(&self) -> Vec<Term> {
+        let on: Vec<Vec<i8>> = self.minterms.iter().map(|b| Term::new(b).bits).collect();
+        let dc: Vec<Vec<i8>> = self.dont_cares.iter().map(|b| Term::new(b).bits).collect();
+        espresso::minimize(&on, &dc, None, self.var_names.len())
+    }
+
+    pub fn format_sop(&self, cover: &[Term]) -> String {
+        if cover.is_empty() {
+            return "0 (FALSE)".to_string();
+        }
+        let mut result_strings: Vec<String> = cover.iter()
+            .map(|t| t.to_string(&self.var_names))
+            .collect();
+        result_strings.sort(); // Sortare alfabetică
+        result_strings.join(" v ")
+    }
+
+    // XOR de produse, ex: "a ⊕ !bc"; un produs fără literali e constanta 1
+    pub fn format_esop(&self, cover: &[Term]) -> String {
+        if cover.is_empty() {
+            return "0 (FALSE)".to_string();
+        }
+        let mut terms: Vec<String> = cover.iter()
+            .map(|t| if t.literal_count() == 0 { "1".to_string() } else { t.to_string(&self.var_names) })
+            .collect();
+        terms.sort_by_key(|t| { let plain = t.replace('!', ""); (plain.len(), plain) });
+        terms.join(" ⊕ ")
+    }
+
+    pub fn format_pos(&self, cover: &[Term]) -> String {
+        if cover.is_empty() {
+            return "1 (TRUE)".to_string();
+        }
+        let mut clauses: Vec<String> = cover.iter()
+            .map(|t| t.to_clause_string(&self.var_names))
+            .collect();
+        clauses.sort();
+        clauses.concat()
+    }
+
+    pub fn simplify(&self) {
+        if self.minterms.is_empty() {
+            println!("Funcția este mereu 0 (Fals).");
+            return;
+        }
+
+        let (cover, exact) = self.sop_cover_checked();
+        println!("\n=== REZULTAT SIMPLIFICARE ===");
+        println!("{}", self.format_sop(&cover).bold().cyan());
+        print_cost(Cost::of(&cover));
+        warn_if_inexact(exact);
+        self.print_sop_groups(&cover);
+    }
+
+    fn print_sop_groups(&self, cover: &[Term]) {
+        let labels: Vec<String> = cover.iter().map(|t| t.to_string(&self.var_names)).collect();
+        self.print_veitch_groups(cover, &labels);
+    }
+
+    // Simplificarea SOP precedată de tabelele Quine-McCluskey, pas cu pas
+    pub fn simplify_verbose(&self) {
+        qm::print_steps(&self.var_names, &self.minterm_indices(), &self.dont_care_indices());
+        self.simplify();
+    }
+
+    pub fn simplify_heuristic(&self) {
+        let cover = self.espresso_cover();
+        println!("\n=== REZULTAT SIMPLIFICARE (ESPRESSO) ===");
+        println!("{}", self.format_sop(&cover).bold().cyan());
+        print_cost(Cost::of(&cover));
+        self.print_sop_groups(&cover);
+    }
+
+    pub fn esop_cover(&self) -> Vec<Term> {
+        esop::minimize(self.var_names.len(), &self.minterm_indices(), &self.dont_care_indices())
+    }
+
+    // ESOP (XOR de produse) comparat cu SOP-ul minim
+    pub fn simplify_esop(&self) {
+        let esop = self.esop_cover();
+        let sop = self.sop_cover();
+        let (esop_cost, sop_cost) = (Cost::of(&esop), Cost::of(&sop));
+
+        println!("\n=== REZULTAT SIMPLIFICARE (ESOP) ===");
+        println!("{}", self.format_esop(&esop).bold().cyan());
+        print_cost(esop_cost);
+        println!("SOP: {}", self.format_sop(&sop));
+        print_cost(sop_cost);
+
+        let verdict = match (esop_cost.inputs, esop_cost.gates).cmp(&(sop_cost.inputs, sop_cost.gates)) {
+            std::cmp::Ordering::Less => "ESOP este mai ieftină.".green(),
+            std::cmp::Ordering::Greater => "SOP este mai ieftină.".green(),
+            std::cmp::Ordering::Equal => "Cele două forme au același cost.".yellow(),
+        };
+        println!("{}", verdict.bold());
+    }
+
+    // Forma multi-nivel obținută prin factorizarea algebrică a SOP-ului minim
+    pub fn factored(&self) -> Option<Formula> {
+        factor::factor(&self.var_names, &self.sop_cover())
+    }
+
+    pub fn simplify_factored(&self) {
+        let cover = self.sop_cover();
+        println!("\n=== FACTORIZARE MULTI-NIVEL ===");
+        let before: usize = cover.iter().map(|t| t.literal_count()).sum();
+        println!("SOP:         {}  {}", self.format_sop(&cover).cyan(), format!("({} literali)", before).dimmed());
+
+        let Some(formula) = self.factored() else {
+            println!("Funcția este constantă, nu are ce factoriza.");
+            return;
+        };
+        let after = factor::formula_literals(&formula);
+        println!("Factorizată: {}  {}", factor::to_compact(&formula).bold().cyan(), format!("({} literali)", after).dimmed());
+        println!("Formula:     {}", formula.to_string().yellow());
+        if after < before {
+            println!("{}", format!("Câștig: {} literali", before - after).green().bold());
+        } else {
+            println!("{}", "SOP-ul minim nu are factori comuni.".yellow());
+        }
+    }
+
+    pub fn simplify_pos(&self) {
+        let (cover, exact) = self.pos_cover_checked();
+        println!("\n=== REZULTAT SIMPLIFICARE (POS) ===");
+        println!("{}", self.format_pos(&cover).bold().cyan());
+        print_cost(Cost::of(&cover));
+        warn_if_inexact(exact);
+
+        // Grupurile de zerouri, etichetate cu clauza corespunzătoare
+        let labels: Vec<String> = cover.iter().map(|t| t.to_clause_string(&self.var_names)).collect();
+        self.print_veitch_groups(&cover, &labels);
+    }
+
+    // Afișează ambele forme minime și care dintre ele e mai ieftină
+    pub fn compare_forms(&self) {
+        let (sop, sop_exact) = self.sop_cover_checked();
+        let (pos, pos_exact) = self.pos_cover_checked();
+        let (sop_cost, pos_cost) = (Cost::of(&sop), Cost::of(&pos));
+
+        println!("\n=== COMPARAȚIE SOP vs POS ===");
+        println!("SOP: {}", self.format_sop(&sop).bold().cyan());
+        print_cost(sop_cost);
+        warn_if_inexact(sop_exact);
+        println!("POS: {}", self.format_pos(&pos).bold().cyan());
+        print_cost(pos_cost);
+        warn_if_inexact(pos_exact);
+
+        let verdict = match (sop_cost.inputs, sop_cost.gates).cmp(&(pos_cost.inputs, pos_cost.gates)) {
+            std::cmp::Ordering::Less => "SOP este mai ieftină.".green(),
+            std::cmp::Ordering::Greater => "POS este mai ieftină.".green(),
+            std::cmp::Ordering::Equal => "Cele două forme au același cost.".yellow(),
+        };
+        println!("{}", verdict.bold());
+    }
+
+    // Hazardurile statice-1 ale acoperirii SOP minime și acoperirea fără hazard
+    pub fn analyze_hazards(&self) {
+        let n = self.var_names.len();
+        let ones = self.minterm_indices();
+        let cover = self.sop_cover();
+        let hazards = hazard::find_hazards(&cover, &ones, n);
+
+        println!("\n=== HAZARDURI STATICE-1 ===");
+        println!("SOP minimă: {}", self.format_sop(&cover).bold().cyan());
+        if hazards.is_empty() {
+            println!("{}", "Nicio tranziție între mintermi adiacenți nu are hazard static-1.".green().bold());
+            return;
+        }
+
+        for h in &hazards {
+            println!("  m{} ({}) ↔ m{} ({}): {} se schimbă, niciun termen nu acoperă ambii mintermi (lipsește {})",
+                h.from, Term::from_index(h.from, n).bit_string(),
+                h.to, Term::from_index(h.to, n).bit_string(),
+                self.var_names[h.var].bold(),
+                h.cube(n).to_string(&self.var_names).yellow());
+        }
+        println!("{}", format!("{} tranziții cu hazard", hazards.len()).red().bold());
+
+        let safe = hazard::hazard_free_cover(&cover, &ones, &self.dont_care_indices(), n);
+        let added: Vec<String> = safe[cover.len()..].iter().map(|t| t.to_string(&self.var_names)).collect();
+        println!("\nTermeni de consens adăugați: {}", added.join(", ").yellow());
+        println!("Acoperire fără hazard: {}", self.format_sop(&safe).bold().green());
+        print_cost(Cost::of(&safe));
+    }
+
+    // Circuitul cu un singur tip de poartă: NAND-NAND din SOP sau NOR-NOR din POS
+    pub fn circuit(&self, kind: GateKind) -> Netlist {
+        match kind {
+            GateKind::Nand => Netlist::nand_from_sop(&self.var_names, &self.sop_cover()),
+            GateKind::Nor => Netlist::nor_from_pos(&self.var_names, &self.pos_cover()),
+        }
+    }
+
+    pub fn print_circuits(&self) {
+        println!("\nSOP: {}", self.format_sop(&self.sop_cover()).bold().cyan());
+        self.circuit(GateKind::Nand).print();
+        println!("\nPOS: {}", self.format_pos(&self.pos_cover()).bold().cyan());
+        self.circuit(GateKind::Nor).print();
+    }
+}
+
+fn print_cost(cost: Cost) {
+    println!("{}", format!("Cost: {} porți, {} intrări de poartă", cost.gates, cost.inputs).dimmed());
+}
+
+fn warn_if_inexact(exact: bool) {
+    if !exact {
+        println!("{}", "Atenție: rezultat posibil neminim (metoda lui Petrick a fost limitată; încearcă --espresso).".yellow());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    #[test]
+    fn from_formula_collects_true_rows() {
+        let func = BooleanFunction::from_formula(&parse_formula("(A & B) | (A & !B & C)"));
+        assert_eq!(func.var_names, vec!["A", "B", "C"]);
+        assert_eq!(func.minterm_indices(), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn formula_is_minimized() {
+        let func = BooleanFunction::from_formula(&parse_formula("(A & B) | (A & !B & C)"));
+        let mut cover: Vec<String> = func.sop_cover().iter().map(|t| t.to_string(&func.var_names)).collect();
+        cover.sort();
+        assert_eq!(cover, vec!["AB", "AC"]);
+    }
+
+    #[test]
+    fn pos_cover_groups_zeros() {
+        // Zerourile 0, 1, 2 ale lui f(A,B,C) dau (A + B)(A + C)
+        let names = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+        let maxterms = [0, 1, 2].iter().map(|&i| bits_of(i, 3)).collect();
+        let func = BooleanFunction::from_maxterms(names, maxterms, Vec::new());
+        assert_eq!(func.minterm_indices(), vec![3, 4, 5, 6, 7]);
+        assert_eq!(func.format_pos(&func.pos_cover()), "(A + B)(A + C)");
+    }
+
+    #[test]
+    fn dont_cares_are_not_minterms() {
+        let names = vec!["A".to_string(), "B".to_string()];
+        let func = BooleanFunction::with_dont_cares(names, vec![bits_of(3, 2)], vec![bits_of(2, 2)]);
+        assert_eq!(func.dont_care_indices(), vec![2]);
+        assert_eq!(func.maxterm_indices(), vec![0, 1]);
+        assert_eq!(func.format_sop(&func.sop_cover()), "A");
+    }
+
+    #[test]
+    fn contradiction_has_no_minterms() {
+        let func = BooleanFunction::from_formula(&parse_formula("A & !A"));
+        assert!(func.minterms.is_empty());
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use colored::*;
+
+use super::export::CoverOutput;
+use super::pla::Pla;
+use super::term::Term;
+use super::{BooleanFunction, Cost};
+
+// O ieșire a funcției: mintermii și don't care-urile ei (indici în tabelul de adevăr)
+#[derive(Debug, Clone)]
+pub struct Output {
+    pub name: String,
+    pub ones: Vec<usize>,
+    pub dont_cares: Vec<usize>,
+}
+
+// Implicant prim multi-ieșire: cubul și mulțimea ieșirilor (bitmask) pe care le poate servi
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaggedTerm {
+    pub bits: Vec<i8>,
+    pub outputs: u64,
+}
+
+// Acoperirea aleasă: termenii produs distincți și, pentru fiecare ieșire, indicii termenilor folosiți
+pub struct MultiCover {
+    pub terms: Vec<Term>,
+    pub per_output: Vec<Vec<usize>>,
+}
+
+pub struct MultiOutputFunction {
+    pub var_names: Vec<String>,
+    pub outputs: Vec<Output>,
+}
+
+// Ieșirile unui termen sunt ținute într-un bitmask de 64 de biți
+const MAX_OUTPUTS: usize = 64;
+
+impl MultiOutputFunction {
+    pub fn new(var_names: Vec<String>, outputs: Vec<Output>) -> Result<Self, String> {
+        if outputs.len() > MAX_OUTPUTS {
+            return Err(format!("Sunt suportate cel mult {} de ieșiri (funcția are {})", MAX_OUTPUTS, outputs.len()));
+        }
+        Ok(MultiOutputFunction { var_names, outputs })
+    }
+
+    // Combină mai multe funcții cu o singură ieșire definite peste aceleași variabile
+    pub fn from_functions(named: Vec<(String, BooleanFunction)>) -> Result<Self, String> {
+        let var_names = named.first().map(|(_, f)| f.var_names.clone()).unwrap_or_default();
+        let mut outputs = Vec::new();
+        for (name, f) in named {
+            if f.var_names != var_names {
+                return Err(format!("Ieșirea {} folosește alte variabile ({:?} în loc de {:?})", name, f.var_names, var_names));
+            }
+            outputs.push(Output { name, ones: f.minterm_indices(), dont_cares: f.dont_care_indices() });
+        }
+        MultiOutputFunction::new(var_names, outputs)
+    }
+
+    pub fn from_pla(pla: &Pla) -> Result<Self, String> {
+        let outputs = (0..pla.output_names.len())
+            .map(|o| {
+                let f = pla.to_function(o);
+                Output { name: pla.output_names[o].clone(), ones: f.minterm_indices(), dont_cares: f.dont_care_indices() }
+            })
+            .collect();
+        MultiOutputFunction::new(pla.input_names.clone(), outputs)
+    }
+
+    // Quine-McCluskey cu etichete: doi termeni se combină doar dacă au ieșiri comune,
+    // iar un termen e bifat numai dacă rezultatul păstrează exact aceeași etichetă.
+    pub fn prime_implicants(&self) -> Vec<TaggedTerm> {
+        let n = self.var_names.len();
+        let mut tags: BTreeMap<usize, u64> = BTreeMap::new();
+        for (o, out) in self.outputs.iter().enumerate() {
+            for &idx in out.ones.iter().chain(&out.dont_cares) {
+                *tags.entry(idx).or_insert(0) |= 1 << o;
+            }
+        }
+
+        let mut terms: Vec<TaggedTerm> = tags.into_iter()
+            .map(|(idx, outputs)| TaggedTerm { bits: Term::from_index(idx, n).bits, outputs })
+            .collect();
+        let mut primes = BTreeSet::new();
+
+        while !terms.is_empty() {
+            let mut next_terms = HashSet::new();
+            let mut checked = vec![false; terms.len()];
+
+            for i in 0..terms.len() {
+                for j in (i + 1)..terms.len() {
+                    let common = terms[i].outputs & terms[j].outputs;
+                    if common == 0 { continue; }
+                    let a = Term { bits: terms[i].bits.clone(), used: false };
+                    let b = Term { bits: terms[j].bits.clone(), used: false };
+                    if let Some(res) = a.combine(&b) {
+                        if common == terms[i].outputs { checked[i] = true; }
+                        if common == terms[j].outputs { checked[j] = true; }
+                        next_terms.insert(TaggedTerm { bits: res.bits, outputs: common });
+                    }
+                }
+            }
+
+            for (i, t) in terms.iter().enumerate() {
+                if !checked[i] {
+                    primes.insert(t.clone());
+                }
+            }
+            terms = next_terms.into_iter().collect();
+        }
+
+        primes.into_iter().collect()
+    }
+
+    // Acoperire comună: implicanții esențiali, apoi alegere greedy care favorizează termenii
+    // deja folosiți de alte ieșiri (o poartă ȘI partajată se plătește o singură dată).
+    pub fn minimize(&self) -> MultiCover {
+        let primes = self.prime_implicants();
+        let as_term = |p: &TaggedTerm| Term { bits: p.bits.clone(), used: false };
+
+        let mut uncovered: BTreeSet<(usize, usize)> = self.outputs.iter().enumerate()
+            .flat_map(|(o, out)| out.ones.iter().map(move |&m| (o, m)))
+            .collect();
+        let covers = |p: &TaggedTerm, (o, m): (usize, usize)| p.outputs & (1 << o) != 0 && as_term(p).covers(m);
+
+        // selected[p] = ieșirile care folosesc implicantul p
+        let mut selected: Vec<u64> = vec![0; primes.len()];
+
+        for &(o, m) in &uncovered {
+            let candidates: Vec<usize> = (0..primes.len()).filter(|&p| covers(&primes[p], (o, m))).collect();
+            if candidates.len() == 1 {
+                selected[candidates[0]] |= 1 << o;
+            }
+        }
+        uncovered.retain(|&(o, m)| !(0..primes.len()).any(|p| selected[p] & (1 << o) != 0 && as_term(&primes[p]).covers(m)));
+
+        while !uncovered.is_empty() {
+            let mut best: Option<(usize, u64, f64)> = None;
+            for (p, prime) in primes.iter().enumerate() {
+                let mut gained = 0;
+                let mut new_outputs = 0u64;
+                for &(o, m) in &uncovered {
+                    if covers(prime, (o, m)) {
+                        gained += 1;
+                        new_outputs |= 1 << o;
+                    }
+                }
+                if gained == 0 { continue; }
+
+                let and_cost = if selected[p] != 0 { 0 } else { as_term(prime).literal_count().max(1) };
+                let or_cost = (new_outputs & !selected[p]).count_ones() as usize;
+                let score = gained as f64 / (and_cost + or_cost) as f64;
+                if best.is_none_or(|(_, _, s)| score > s) {
+                    best = Some((p, new_outputs, score));
+                }
+            }
+
+            let (p, outs, _) = best.expect("Orice minterm e acoperit de cel puțin un implicant");
+            selected[p] |= outs;
+            let term = as_term(&primes[p]);
+            uncovered.retain(|&(o, m)| !(outs & (1 << o) != 0 && term.covers(m)));
+        }
+
+        // Elimină utilizările redundante, începând cu termenii cei mai scumpi
+        let mut order: Vec<usize> = (0..primes.len()).filter(|&p| selected[p] != 0).collect();
+        order.sort_by_key(|&p| std::cmp::Reverse(as_term(&primes[p]).literal_count()));
+        for &p in &order {
+            for o in 0..self.outputs.len() {
+                if selected[p] & (1 << o) == 0 { continue; }
+                selected[p] &= !(1 << o);
+                let still_covered = self.outputs[o].ones.iter().all(|&m| {
+                    (0..primes.len()).any(|q| selected[q] & (1 << o) != 0 && as_term(&primes[q]).covers(m))
+                });
+                if !still_covered {
+                    selected[p] |= 1 << o;
+                }
+            }
+        }
+
+        let used: Vec<usize> = (0..primes.len()).filter(|&p| selected[p] != 0).collect();
+        let terms = used.iter().map(|&p| as_term(&primes[p])).collect();
+        let per_output = (0..self.outputs.len())
+            .map(|o| (0..used.len()).filter(|&i| selected[used[i]] & (1 << o) != 0).collect())
+            .collect();
+
+        MultiCover { terms, per_output }
+    }
+
+    // Costul circuitului comun: fiecare termen produs distinct are o singură poartă ȘI
+    pub fn cover_cost(&self, cover: &MultiCover) -> Cost {
+        let mut gates = 0;
+        let mut inputs = 0;
+        for t in &cover.terms {
+            if t.literal_count() > 1 {
+                gates += 1;
+                inputs += t.literal_count();
+            }
+        }
+        for terms in &cover.per_output {
+            if terms.len() > 1 {
+                gates += 1;
+                inputs += terms.len();
+            }
+        }
+        Cost { gates, inputs }
+    }
+
+    // Acoperirea fiecărei ieșiri, pentru exportul Verilog / BLIF
+    pub fn cover_outputs(&self, cover: &MultiCover) -> Vec<CoverOutput> {
+        self.outputs.iter().enumerate()
+            .map(|(o, out)| CoverOutput {
+                name: out.name.clone(),
+                cover: cover.per_output[o].iter().map(|&i| cover.terms[i].clone()).collect(),
+                pos: false,
+            })
+            .collect()
+    }
+
+    fn format_output(&self, cover: &MultiCover, o: usize) -> String {
+        if cover.per_output[o].is_empty() {
+            return "0 (FALSE)".to_string();
+        }
+        let mut parts: Vec<String> = cover.per_output[o].iter()
+            .map(|&i| cover.terms[i].to_string(&self.var_names))
+            .collect();
+        parts.sort();
+        parts.join(" v ")
+    }
+
+    pub fn simplify(&self) {
+        let cover = self.minimize();
+
+        println!("\n=== REZULTAT MINIMIZARE MULTI-IEȘIRE ===");
+        for (o, out) in self.outputs.iter().enumerate() {
+            let single = Cost::of(&cover.per_output[o].iter().map(|&i| cover.terms[i].clone()).collect::<Vec<_>>());
+            println!("{} = {}", out.name.bold(), self.format_output(&cover, o).cyan().bold());
+            println!("{}", format!("    {} termeni, {} porți, {} intrări de poartă", cover.per_output[o].len(), single.gates, single.inputs).dimmed());
+        }
+
+        let shared: Vec<String> = (0..cover.terms.len())
+            .filter(|&i| cover.per_output.iter().filter(|ts| ts.contains(&i)).count() > 1)
+            .map(|i| {
+                let users: Vec<&str> = (0..self.outputs.len())
+                    .filter(|&o| cover.per_output[o].contains(&i))
+                    .map(|o| self.outputs[o].name.as_str())
+                    .collect();
+                format!("{} ({})", cover.terms[i].to_string(&self.var_names), users.join(", "))
+            })
+            .collect();
+        if !shared.is_empty() {
+            println!("\nTermeni partajați: {}", shared.join("; ").yellow());
+        }
+
+        let total = self.cover_cost(&cover);
+        let separate = self.outputs.iter()
+            .map(|out| {
+                let f = BooleanFunction::with_dont_cares(
+                    self.var_names.clone(),
+                    out.ones.iter().map(|&i| super::bits_of(i, self.var_names.len())).collect(),
+                    out.dont_cares.iter().map(|&i| super::bits_of(i, self.var_names.len())).collect(),
+                );
+                Cost::of(&f.sop_cover())
+            })
+            .fold(Cost { gates: 0, inputs: 0 }, |acc, c| Cost { gates: acc.gates + c.gates, inputs: acc.inputs + c.inputs });
+
+        println!("\n{}", "=== COST TOTAL ===".bold());
+        println!("Circuit comun:    {} porți, {} intrări de poartă ({} termeni produs distincți)", total.gates, total.inputs, cover.terms.len());
+        println!("Ieșiri separate:  {} porți, {} intrări de poartă", separate.gates, separate.inputs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimizer::input::parse_index_notation;
+
+    fn function(specs: &[&str]) -> MultiOutputFunction {
+        let named = specs.iter()
+            .map(|s| (s.split('(').next().unwrap().trim().to_string(), parse_index_notation(s).unwrap()))
+            .collect();
+        MultiOutputFunction::from_functions(named).unwrap()
+    }
+
+    #[test]
+    fn outputs_share_product_terms() {
+        // f = ac + bc și g = ab + bc: termenul bc e calculat o singură dată
+        let multi = function(&["f(a,b,c) = Σm(3,5,7)", "g(a,b,c) = Σm(3,6,7)"]);
+        let cover = multi.minimize();
+        for (o, out) in multi.outputs.iter().enumerate() {
+            for idx in 0..8 {
+                let covered = cover.per_output[o].iter().any(|&i| cover.terms[i].covers(idx));
+                assert_eq!(covered, out.ones.contains(&idx), "ieșirea {}, linia {}", out.name, idx);
+            }
+        }
+        assert_eq!(cover.terms.len(), 3);
+        let bc = cover.terms.iter().position(|t| t.bits == vec![-1, 1, 1]).expect("bc e în acoperire");
+        assert!(cover.per_output.iter().all(|ts| ts.contains(&bc)));
+    }
+
+    #[test]
+    fn outputs_must_use_the_same_variables() {
+        let named = vec![
+            ("f".to_string(), parse_index_notation("f(a,b) = Σm(1)").unwrap()),
+            ("g".to_string(), parse_index_notation("g(a,c) = Σm(2)").unwrap()),
+        ];
+        assert!(MultiOutputFunction::from_functions(named).is_err());
+    }
+
+    #[test]
+    fn too_many_outputs_is_an_error() {
+        let text = format!(".i 1\n.o 65\n1 {}\n.e\n", "1".repeat(65));
+        let pla = Pla::parse(&text).unwrap();
+        let error = MultiOutputFunction::from_pla(&pla).err().expect("65 de ieșiri trebuie respinse");
+        assert!(error.contains("cel mult 64"));
+        let text = format!(".i 1\n.o 64\n1 {}\n.e\n", "1".repeat(64));
+        assert_eq!(MultiOutputFunction::from_pla(&Pla::parse(&text).unwrap()).unwrap().outputs.len(), 64);
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+use super::{bits_of, BooleanFunction, Term};
+
+// Un fișier Berkeley PLA: cuburi de intrare (0/1/-) și coloanele de ieșire corespunzătoare.
+//   .i 3
+//   .o 1
+//   .ilb a b c
+//   .ob f
+//   01- 1
+//   .e
+#[derive(Debug, Clone)]
+pub struct Pla {
+    pub input_names: Vec<String>,
+    pub output_names: Vec<String>,
+    pub cubes: Vec<(Vec<i8>, Vec<char>)>,
+    // Tipul fișierului (f, fd, fr, fdr); contează doar dacă zerourile sunt date explicit
+    pub kind: String,
+}
+
+impl Pla {
+    pub fn read(path: &str) -> Result<Pla, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Nu pot citi fișierul {}: {}", path, e))?;
+        Pla::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Pla, String> {
+        let mut num_inputs: Option<usize> = None;
+        let mut num_outputs: Option<usize> = None;
+        let mut input_names = Vec::new();
+        let mut output_names = Vec::new();
+        let mut kind = "fd".to_string();
+        let mut cubes = Vec::new();
+
+        for (line_no, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+
+            let mut parts = line.split_whitespace();
+            let head = parts.next().unwrap_or("");
+            let bad_number = || format!("Linia {}: număr invalid în '{}'", line_no + 1, line);
+
+            match head {
+                ".i" => num_inputs = Some(parts.next().and_then(|v| v.parse().ok()).ok_or_else(bad_number)?),
+                ".o" => num_outputs = Some(parts.next().and_then(|v| v.parse().ok()).ok_or_else(bad_number)?),
+                ".ilb" => input_names = parts.map(|s| s.to_string()).collect(),
+                ".ob" => output_names = parts.map(|s| s.to_string()).collect(),
+                ".type" => kind = parts.next().unwrap_or("fd").to_string(),
+                ".e" | ".end" => break,
+                _ if head.starts_with('.') => {} // .p, .phase etc. nu sunt necesare
+                _ => {
+                    let rest: String = parts.collect();
+                    let (inputs, outputs) = match num_inputs {
+                        // Intrările și ieșirile pot fi lipite sau separate prin spații
+                        Some(n) if rest.is_empty() && head.len() > n => (head[..n].to_string(), head[n..].to_string()),
+                        _ => (head.to_string(), rest),
+                    };
+                    let bits = inputs.chars().map(|c| match c {
+                        '0' => Ok(0),
+                        '1' => Ok(1),
+                        '-' | '2' | 'x' | 'X' => Ok(-1),
+                        _ => Err(format!("Linia {}: caracter de intrare invalid '{}'", line_no + 1, c)),
+                    }).collect::<Result<Vec<i8>, String>>()?;
+                    cubes.push((bits, outputs.chars().collect::<Vec<char>>()));
+                }
+            }
+        }
+
+        let n = num_inputs.or_else(|| cubes.first().map(|(b, _)| b.len()))
+            .ok_or("Fișierul PLA nu conține nicio intrare (.i)")?;
+        let m = num_outputs.or_else(|| cubes.first().map(|(_, o)| o.len())).unwrap_or(1);
+
+        for (i, (bits, outs)) in cubes.iter().enumerate() {
+            if bits.len() != n || outs.len() != m {
+                return Err(format!("Cubul {} are dimensiunea greșită (așteptam {} intrări și {} ieșiri)", i + 1, n, m));
+            }
+        }
+
+        if input_names.len() != n {
+            input_names = (0..n).map(|i| format!("x{}", i)).collect();
+        }
+        if output_names.len() != m {
+            output_names = (0..m).map(|i| format!("f{}", i)).collect();
+        }
+
+        Ok(Pla { input_names, output_names, cubes, kind })
+    }
+
+    // Cuburile ON, DC și (dacă tipul le conține) OFF ale ieșirii `output`, fără expandare
+    #[allow(clippy::type_complexity)]
+    pub fn output_cubes(&self, output: usize) -> (Vec<Vec<i8>>, Vec<Vec<i8>>, Option<Vec<Vec<i8>>>) {
+        let mut on = Vec::new();
+        let mut dc = Vec::new();
+        let mut off = Vec::new();
+
+        for (bits, outs) in &self.cubes {
+            match outs[output] {
+                '1' | '4' => on.push(bits.clone()),
+                '-' | '2' => dc.push(bits.clone()),
+                '0' | '3' if self.kind.contains('r') => off.push(bits.clone()),
+                _ => {}
+            }
+        }
+
+        let explicit_off = if self.kind.contains('r') { Some(off) } else { None };
+        (on, dc, explicit_off)
+    }
+
+    // Mintermii, don't care-urile și (dacă tipul le conține) zerourile ieșirii `output`
+    pub fn output_sets(&self, output: usize) -> (Vec<usize>, Vec<usize>, Option<Vec<usize>>) {
+        let mut ones = Vec::new();
+        let mut dcs = Vec::new();
+        let mut zeros = Vec::new();
+
+        for (bits, outs) in &self.cubes {
+            let target = match outs[output] {
+                '1' | '4' => &mut ones,
+                '-' | '2' => &mut dcs,
+                '0' | '3' if self.kind.contains('r') => &mut zeros,
+                _ => continue,
+            };
+            target.extend(expand_cube(bits));
+        }
+
+        let explicit_zeros = if self.kind.contains('r') { Some(zeros) } else { None };
+        (ones, dcs, explicit_zeros)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!(".i {}\n.o {}\n", self.input_names.len(), self.output_names.len()));
+        out.push_str(&format!(".ilb {}\n.ob {}\n", self.input_names.join(" "), self.output_names.join(" ")));
+        out.push_str(&format!(".p {}\n", self.cubes.len()));
+        for (bits, outs) in &self.cubes {
+            let inputs: String = bits.iter().map(|&b| match b { 0 => '0', 1 => '1', _ => '-' }).collect();
+            out.push_str(&format!("{} {}\n", inputs, outs.iter().collect::<String>()));
+        }
+        out.push_str(".e\n");
+        out
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_text()).map_err(|e| format!("Nu pot scrie fișierul {}: {}", path, e))
+    }
+
+    // Funcția dată ca PLA cu o singură ieșire: un cub complet pe minterm sau don't care
+    pub fn from_function(func: &BooleanFunction, output_name: &str) -> Pla {
+        let rows = |list: &[Vec<u8>], value: char| -> Vec<(Vec<i8>, Vec<char>)> {
+            list.iter().map(|bits| (Term::new(bits).bits, vec![value])).collect()
+        };
+        let mut cubes = rows(&func.minterms, '1');
+        cubes.extend(rows(&func.dont_cares, '-'));
+        Pla {
+            input_names: func.var_names.clone(),
+            output_names: vec![output_name.to_string()],
+            cubes,
+            kind: "fd".to_string(),
+        }
+    }
+
+    // Indicele ieșirii cu numele dat
+    pub fn output_index(&self, name: &str) -> Result<usize, String> {
+        self.output_names.iter().position(|o| o == name)
+            .ok_or(format!("Ieșirea '{}' nu există în PLA", name))
+    }
+
+    // Cuburile care au 1 pe ieșirea `output`, ca termeni (acoperirea unui PLA minimizat)
+    pub fn output_cover(&self, output: usize) -> Vec<Term> {
+        self.cubes.iter()
+            .filter(|(_, outs)| outs[output] == '1')
+            .map(|(bits, _)| Term { bits: bits.clone(), used: false })
+            .collect()
+    }
+
+    // Funcția booleană pentru o singură ieșire a fișierului
+    pub fn to_function(&self, output: usize) -> BooleanFunction {
+        let n = self.input_names.len();
+        let (ones, dcs, zeros) = self.output_sets(output);
+        let ones: BTreeSet<usize> = ones.into_iter().collect();
+        let mut dcs: BTreeSet<usize> = dcs.into_iter().collect();
+
+        // Pentru tipurile cu zerouri explicite, liniile nespecificate devin don't care
+        if let Some(zeros) = zeros {
+            let zeros: BTreeSet<usize> = zeros.into_iter().collect();
+            dcs.extend((0..(1usize << n)).filter(|idx| !ones.contains(idx) && !zeros.contains(idx)));
+        }
+
+        BooleanFunction::with_dont_cares(
+            self.input_names.clone(),
+            ones.iter().map(|&i| bits_of(i, n)).collect(),
+            dcs.iter().map(|&i| bits_of(i, n)).collect(),
+        )
+    }
+}
+
+// Toate liniile din tabelul de adevăr acoperite de un cub cu '-'
+pub fn expand_cube(bits: &[i8]) -> Vec<usize> {
+    let mut indices = vec![0usize];
+    for &b in bits {
+        indices = indices.into_iter()
+            .flat_map(|idx| match b {
+                0 => vec![idx << 1],
+                1 => vec![(idx << 1) | 1],
+                _ => vec![idx << 1, (idx << 1) | 1],
+            })
+            .collect();
+    }
+    indices
+}
@@ -0,0 +1,300 @@
+use std::collections::{BTreeSet, HashSet};
+use colored::*;
+
+use super::term::Term;
+
+// Peste acest număr de produse, metoda lui Petrick păstrează doar cele mai ieftine variante
+const PETRICK_LIMIT: usize = 1024;
+
+// Pașii Quine-McCluskey: fiecare pas conține termenii obținuți prin combinare, ordonați după
+// numărul de biți 1; `used` marchează termenii care s-au combinat în pasul următor.
+pub fn passes(num_vars: usize, ones: &[usize], dont_cares: &[usize]) -> Vec<Vec<Term>> {
+    let mut terms: Vec<Term> = ones.iter().chain(dont_cares)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|&idx| Term::from_index(idx, num_vars))
+        .collect();
+    let mut all_passes = Vec::new();
+
+    while !terms.is_empty() {
+        let mut next_terms = HashSet::new();
+
+        for i in 0..terms.len() {
+            for j in (i + 1)..terms.len() {
+                if let Some(res) = terms[i].combine(&terms[j]) {
+                    next_terms.insert(res);
+                    terms[i].used = true;
+                    terms[j].used = true;
+                }
+            }
+        }
+
+        terms.sort_by_key(|t| (t.ones_count(), t.bits.clone()));
+        all_passes.push(terms);
+        terms = next_terms.into_iter().collect();
+    }
+    all_passes
+}
+
+// Implicanții primi (Quine-McCluskey) pentru mintermi + don't care-uri
+pub fn prime_implicants(num_vars: usize, ones: &[usize], dont_cares: &[usize]) -> Vec<Term> {
+    let unique: BTreeSet<Vec<i8>> = passes(num_vars, ones, dont_cares).into_iter()
+        .flatten()
+        .filter(|t| !t.used)
+        .map(|t| t.bits)
+        .collect();
+    unique.into_iter().map(|bits| Term { bits, used: false }).collect()
+}
+
+// Implicanții esențiali: singurii care acoperă cel puțin un minterm
+pub fn essential_primes(primes: &[Term], ones: &[usize]) -> BTreeSet<usize> {
+    let mut essential = BTreeSet::new();
+    for &m in ones {
+        let covering: Vec<usize> = (0..primes.len()).filter(|&p| primes[p].covers(m)).collect();
+        if covering.len() == 1 {
+            essential.insert(covering[0]);
+        }
+    }
+    essential
+}
+
+// Acoperirea minimă a mintermilor: implicanții esențiali, apoi metoda lui Petrick pentru rest
+pub fn minimal_cover(primes: &[Term], ones: &[usize]) -> Vec<Term> {
+    minimal_cover_checked(primes, ones).0
+}
+
+// Ca `minimal_cover`, plus dacă minimalitatea e garantată (false când produsele lui Petrick au fost trunchiate)
+pub fn minimal_cover_checked(primes: &[Term], ones: &[usize]) -> (Vec<Term>, bool) {
+    cover_with_limit(primes, ones, PETRICK_LIMIT)
+}
+
+fn cover_with_limit(primes: &[Term], ones: &[usize], limit: usize) -> (Vec<Term>, bool) {
+    let mut exact = true;
+    let mut chosen = essential_primes(primes, ones);
+
+    let remaining: Vec<usize> = ones.iter().copied()
+        .filter(|&m| !chosen.iter().any(|&p| primes[p].covers(m)))
+        .collect();
+
+    let mut products: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+    for &m in &remaining {
+        let covering: Vec<usize> = (0..primes.len()).filter(|&p| primes[p].covers(m)).collect();
+        let mut next: Vec<BTreeSet<usize>> = Vec::new();
+
+        for product in &products {
+            if covering.iter().any(|p| product.contains(p)) {
+                next.push(product.clone());
+                continue;
+            }
+            for &p in &covering {
+                let mut extended = product.clone();
+                extended.insert(p);
+                next.push(extended);
+            }
+        }
+
+        // Produsele cele mai ieftine sunt păstrate înainte de absorbție, care e pătratică
+        next.sort();
+        next.dedup();
+        next.sort_by_key(|p| product_cost(primes, p));
+        if next.len() > limit {
+            next.truncate(limit);
+            exact = false;
+        }
+
+        // Absorbție: X + XY = X (doar un produs mai scurt poate absorbi)
+        let mut absorbed: Vec<BTreeSet<usize>> = Vec::new();
+        for p in next {
+            if !absorbed.iter().any(|q| q.len() < p.len() && q.is_subset(&p)) {
+                absorbed.push(p);
+            }
+        }
+        products = absorbed;
+    }
+
+    let best = products.into_iter()
+        .min_by_key(|p| product_cost(primes, p))
+        .unwrap_or_default();
+    chosen.extend(best);
+
+    (chosen.into_iter().map(|p| primes[p].clone()).collect(), exact)
+}
+
+fn product_cost(primes: &[Term], product: &BTreeSet<usize>) -> (usize, usize) {
+    let literals = product.iter().map(|&p| primes[p].literal_count()).sum();
+    (product.len(), literals)
+}
+
+fn index_list(indices: &[usize]) -> String {
+    format!("m({})", indices.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(","))
+}
+
+// Afișează tabelele clasice Quine-McCluskey: fiecare pas grupat după numărul de biți 1,
+// cu bifă pe termenii combinați, implicanții primi și tabelul de acoperire cu esențialii evidențiați.
+pub fn print_steps(var_names: &[String], ones: &[usize], dont_cares: &[usize]) {
+    let all_passes = passes(var_names.len(), ones, dont_cares);
+
+    for (k, pass) in all_passes.iter().enumerate() {
+        println!("\n{}", format!("=== PASUL {} ===", k + 1).purple().bold());
+        let width = pass.iter().map(|t| index_list(&t.indices()).len()).max().unwrap_or(0);
+
+        let mut current_group = None;
+        for t in pass {
+            if current_group != Some(t.ones_count()) {
+                current_group = Some(t.ones_count());
+                println!("{}", format!("Grupa {} ({} biți de 1):", t.ones_count(), t.ones_count()).blue().bold());
+            }
+            let mark = if t.used { "✓".green().bold() } else { "*".yellow().bold() };
+            println!("  {:<w$}  {}  {}", index_list(&t.indices()), t.bit_string(), mark, w = width);
+        }
+    }
+    println!("{}", "(✓ = combinat în pasul următor, * = implicant prim)".dimmed());
+
+    let primes = prime_implicants(var_names.len(), ones, dont_cares);
+    let essential = essential_primes(&primes, ones);
+
+    println!("\n{}", "=== IMPLICANȚI PRIMI ===".purple().bold());
+    for (p, t) in primes.iter().enumerate() {
+        let line = format!("P{:<2} {}  {}  {}", p + 1, t.bit_string(), index_list(&t.indices()), t.to_string(var_names));
+        if essential.contains(&p) {
+            println!("{} {}", line.green().bold(), "(esențial)".green());
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    if ones.is_empty() { return; }
+
+    // Tabelul de acoperire: rânduri = implicanți primi, coloane = mintermi (fără don't care-uri)
+    println!("\n{}", "=== TABELUL DE ACOPERIRE ===".purple().bold());
+    let col_width = ones.iter().map(|m| m.to_string().len()).max().unwrap_or(1).max(2) + 1;
+    let header: String = ones.iter().map(|m| format!("{:>w$}", m, w = col_width)).collect();
+    println!("{:<6}|{}", "", header);
+    println!("{}", "-".repeat(6 + 1 + col_width * ones.len()));
+
+    for (p, t) in primes.iter().enumerate() {
+        print!("{:<6}|", format!("P{}", p + 1));
+        for &m in ones {
+            let sole = (0..primes.len()).filter(|&q| primes[q].covers(m)).count() == 1;
+            let cell = if !t.covers(m) {
+                " ".repeat(col_width)
+            } else if sole {
+                format!("{:>w$}", "⊗", w = col_width).green().bold().to_string()
+            } else if essential.contains(&p) {
+                format!("{:>w$}", "X", w = col_width).green().to_string()
+            } else {
+                format!("{:>w$}", "X", w = col_width)
+            };
+            print!("{}", cell);
+        }
+        println!();
+    }
+    println!("{}", "(⊗ = singurul implicant care acoperă mintermul → implicant esențial)".dimmed());
+
+    let remaining: Vec<usize> = ones.iter().copied()
+        .filter(|&m| !essential.iter().any(|&p| primes[p].covers(m)))
+        .collect();
+    if remaining.is_empty() {
+        println!("Implicanții esențiali acoperă toți mintermii.");
+    } else {
+        println!("Mintermi rămași după esențiali: {} (aleși cu metoda lui Petrick)", index_list(&remaining).yellow());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Acoperirea trebuie să conțină toți mintermii și niciun zero
+    fn assert_exact_cover(cover: &[Term], ones: &[usize], dont_cares: &[usize], num_vars: usize) {
+        for idx in 0..(1usize << num_vars) {
+            let covered = cover.iter().any(|t| t.covers(idx));
+            if ones.contains(&idx) {
+                assert!(covered, "mintermul {} nu e acoperit", idx);
+            } else if !dont_cares.contains(&idx) {
+                assert!(!covered, "zeroul {} e acoperit", idx);
+            }
+        }
+    }
+
+    #[test]
+    fn prime_implicants_of_small_function() {
+        // f(a,b,c) = Σm(0,1,2,5,6,7): implicanții primi sunt cele 6 perechi adiacente
+        let primes = prime_implicants(3, &[0, 1, 2, 5, 6, 7], &[]);
+        let bits: Vec<String> = primes.iter().map(|t| t.bit_string()).collect();
+        assert_eq!(bits, vec!["-01", "-10", "0-0", "00-", "1-1", "11-"]);
+    }
+
+    #[test]
+    fn passes_record_combined_terms() {
+        // Toate cele 4 linii: pasul 2 are 4 perechi, pasul 3 doar cubul „--”, singurul implicant prim
+        let all = passes(2, &[0, 1, 2, 3], &[]);
+        let bits = |k: usize| all[k].iter().map(|t| t.bit_string()).collect::<Vec<_>>();
+        assert_eq!(all.len(), 3);
+        assert_eq!(bits(0), vec!["00", "01", "10", "11"]);
+        assert_eq!(bits(1), vec!["-0", "0-", "-1", "1-"]);
+        assert_eq!(bits(2), vec!["--"]);
+        assert!(all[0].iter().chain(&all[1]).all(|t| t.used));
+        assert!(!all[2][0].used);
+    }
+
+    #[test]
+    fn essential_primes_cover_unique_minterms() {
+        // f = Σm(0,1,5,7): !a!b și ac sunt esențiali, !bc (1,5) nu
+        let ones = [0, 1, 5, 7];
+        let primes = prime_implicants(3, &ones, &[]);
+        let essential: Vec<String> = essential_primes(&primes, &ones).iter().map(|&p| primes[p].bit_string()).collect();
+        assert_eq!(essential, vec!["00-", "1-1"]);
+    }
+
+    #[test]
+    fn cyclic_chart_is_solved_by_petrick() {
+        // Niciun implicant esențial; acoperirea minimă are 3 termeni
+        let ones = [0, 1, 2, 5, 6, 7];
+        let primes = prime_implicants(3, &ones, &[]);
+        assert!(essential_primes(&primes, &ones).is_empty());
+        let cover = minimal_cover(&primes, &ones);
+        assert_eq!(cover.len(), 3);
+        assert_exact_cover(&cover, &ones, &[], 3);
+    }
+
+    #[test]
+    fn truncated_petrick_is_reported() {
+        let ones = [0, 1, 2, 5, 6, 7];
+        let primes = prime_implicants(3, &ones, &[]);
+        assert!(minimal_cover_checked(&primes, &ones).1);
+        let (cover, exact) = cover_with_limit(&primes, &ones, 1);
+        assert!(!exact);
+        assert_exact_cover(&cover, &ones, &[], 3);
+    }
+
+    #[test]
+    fn large_cyclic_charts_stay_bounded() {
+        // Liniile unei funcții de 6 variabile, alese pseudoaleator: tabelul de acoperire
+        // poate rămâne ciclic după esențiali, iar o limită mică trebuie raportată
+        let mut state = 0x0123_4567_u64;
+        let ones: Vec<usize> = (0..64).filter(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state.is_multiple_of(2)
+        }).collect();
+        let primes = prime_implicants(6, &ones, &[]);
+        let (cover, _) = minimal_cover_checked(&primes, &ones);
+        assert_exact_cover(&cover, &ones, &[], 6);
+        let (small, exact) = cover_with_limit(&primes, &ones, 8);
+        assert!(!exact);
+        assert_exact_cover(&small, &ones, &[], 6);
+        assert!(small.len() >= cover.len());
+    }
+
+    #[test]
+    fn dont_cares_enlarge_implicants() {
+        // f(a,b,c,d) = Σm(1,3,7,11,15) + d(0,2,5): cd și !a!b sunt suficienți
+        let (ones, dcs) = ([1, 3, 7, 11, 15], [0, 2, 5]);
+        let primes = prime_implicants(4, &ones, &dcs);
+        let cover = minimal_cover(&primes, &ones);
+        assert_eq!(cover.len(), 2);
+        assert_exact_cover(&cover, &ones, &dcs, 4);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use colored::*;
+
+use super::term::Term;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateKind {
+    Nand,
+    Nor,
+}
+
+impl GateKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GateKind::Nand => "NAND",
+            GateKind::Nor => "NOR",
+        }
+    }
+}
+
+// Un semnal din circuit: o intrare, ieșirea unei porți sau o constantă
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Input(usize),
+    Gate(usize),
+    Const(bool),
+}
+
+#[derive(Debug, Clone)]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: Vec<Signal>,
+}
+
+// Circuit pe două niveluri realizat doar cu porți NAND (din SOP) sau doar NOR (din POS).
+// Inversoarele sunt porți cu o singură intrare de același tip.
+pub struct Netlist {
+    pub kind: GateKind,
+    pub input_names: Vec<String>,
+    pub output_name: String,
+    pub gates: Vec<Gate>,
+    pub output: Signal,
+}
+
+impl Netlist {
+    // SOP → NAND-NAND: fiecare produs devine un NAND, iar ieșirea e NAND-ul produselor
+    pub fn nand_from_sop(var_names: &[String], cover: &[Term]) -> Netlist {
+        // În SOP, bitul 1 e literalul x, bitul 0 e !x
+        Netlist::two_level(GateKind::Nand, var_names, cover, |b| b == 1)
+    }
+
+    // POS → NOR-NOR: fiecare clauză devine un NOR, iar ieșirea e NOR-ul clauzelor.
+    // Cuburile acoperă zerourile: bitul 0 e literalul x, bitul 1 e !x.
+    pub fn nor_from_pos(var_names: &[String], cover: &[Term]) -> Netlist {
+        Netlist::two_level(GateKind::Nor, var_names, cover, |b| b == 0)
+    }
+
+    fn two_level(kind: GateKind, var_names: &[String], cover: &[Term], positive: impl Fn(i8) -> bool) -> Netlist {
+        let mut net = Netlist {
+            kind,
+            input_names: var_names.to_vec(),
+            output_name: "f".to_string(),
+            gates: Vec::new(),
+            output: Signal::Const(false),
+        };
+        // SOP gol = 0, POS gol = 1; un termen fără literali dă constanta opusă
+        let identity = kind == GateKind::Nor;
+        if cover.is_empty() {
+            net.output = Signal::Const(identity);
+            return net;
+        }
+        if cover.iter().any(|t| t.literal_count() == 0) {
+            net.output = Signal::Const(!identity);
+            return net;
+        }
+
+        let mut inverters: HashMap<usize, usize> = HashMap::new();
+        // Literalul (variabilă, pozitiv) ca semnal
+        let mut literal = |net: &mut Netlist, var: usize, pos: bool| -> Signal {
+            if pos { return Signal::Input(var); }
+            let g = *inverters.entry(var).or_insert_with(|| net.add(vec![Signal::Input(var)]));
+            Signal::Gate(g)
+        };
+
+        let literals = |t: &Term| -> Vec<(usize, bool)> {
+            t.bits.iter().enumerate()
+                .filter(|(_, &b)| b != -1)
+                .map(|(i, &b)| (i, positive(b)))
+                .collect()
+        };
+
+        // Un singur literal: ieșirea e intrarea însăși sau inversorul ei
+        if cover.len() == 1 && cover[0].literal_count() == 1 {
+            let (var, pos) = literals(&cover[0])[0];
+            net.output = literal(&mut net, var, pos);
+            return net;
+        }
+
+        let mut first_level = Vec::new();
+        for t in cover {
+            let lits = literals(t);
+            if lits.len() == 1 {
+                // Un singur literal l intră direct în poarta de ieșire ca !l
+                let (var, pos) = lits[0];
+                first_level.push(literal(&mut net, var, !pos));
+            } else {
+                let inputs = lits.iter().map(|&(var, pos)| literal(&mut net, var, pos)).collect();
+                first_level.push(Signal::Gate(net.add(inputs)));
+            }
+        }
+
+        // Cu un singur termen, poarta de ieșire e un inversor: NAND(NAND(x, y)) = AND(x, y)
+        let out = net.add(first_level);
+        net.output = Signal::Gate(out);
+        net
+    }
+
+    fn add(&mut self, inputs: Vec<Signal>) -> usize {
+        self.gates.push(Gate { kind: self.kind, inputs });
+        self.gates.len() - 1
+    }
+
+    fn signal_name(&self, s: Signal) -> String {
+        match s {
+            Signal::Input(i) => self.input_names[i].clone(),
+            Signal::Gate(g) => format!("g{}", g + 1),
+            Signal::Const(b) => if b { "1".to_string() } else { "0".to_string() },
+        }
+    }
+
+    pub fn inverter_count(&self) -> usize {
+        self.gates.iter().filter(|g| g.inputs.len() == 1).count()
+    }
+
+    pub fn max_fan_in(&self) -> usize {
+        self.gates.iter().map(|g| g.inputs.len()).max().unwrap_or(0)
+    }
+
+    pub fn print(&self) {
+        println!("\n=== CIRCUIT {0}-{0} ===", self.kind.name());
+        println!("Intrări: {}", self.input_names.join(" "));
+        for (i, g) in self.gates.iter().enumerate() {
+            let inputs: Vec<String> = g.inputs.iter().map(|&s| self.signal_name(s)).collect();
+            let line = format!("g{} = {}({})", i + 1, g.kind.name(), inputs.join(", "));
+            if g.inputs.len() == 1 {
+                println!("{}  {}", line, "[inversor]".dimmed());
+            } else {
+                println!("{}", line);
+            }
+        }
+        println!("{} = {}", self.output_name.bold(), self.signal_name(self.output).cyan().bold());
+        println!("{}", format!("Porți: {} {} ({} inversoare), fan-in maxim {}",
+            self.gates.len(), self.kind.name(), self.inverter_count(), self.max_fan_in()).dimmed());
+    }
+
+    // Circuitul în format Graphviz (dot -Tpng circuit.dot -o circuit.png)
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+        for name in &self.input_names {
+            dot.push_str(&format!("    \"{}\" [shape=circle];\n", name));
+        }
+        for (i, g) in self.gates.iter().enumerate() {
+            dot.push_str(&format!("    g{} [shape=box, label=\"{}\"];\n", i + 1, g.kind.name()));
+            for &s in &g.inputs {
+                dot.push_str(&format!("    \"{}\" -> g{};\n", self.signal_name(s), i + 1));
+            }
+        }
+        dot.push_str(&format!("    \"{}\" [shape=doublecircle];\n", self.output_name));
+        dot.push_str(&format!("    \"{}\" -> \"{}\";\n", self.signal_name(self.output), self.output_name));
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimizer::{bits_of, BooleanFunction};
+
+    // Simulează circuitul (porțile sunt create în ordine topologică)
+    fn simulate(net: &Netlist, row: &[u8]) -> bool {
+        let mut values: Vec<bool> = Vec::new();
+        let value = |values: &[bool], s: Signal| match s {
+            Signal::Input(i) => row[i] == 1,
+            Signal::Gate(g) => values[g],
+            Signal::Const(b) => b,
+        };
+        for g in &net.gates {
+            let inputs: Vec<bool> = g.inputs.iter().map(|&s| value(&values, s)).collect();
+            values.push(match g.kind {
+                GateKind::Nand => !inputs.iter().all(|&v| v),
+                GateKind::Nor => !inputs.iter().any(|&v| v),
+            });
+        }
+        value(&values, net.output)
+    }
+
+    #[test]
+    fn circuits_compute_the_function() {
+        let mut state = 0x2545f4914f6cdd1du64;
+        for _ in 0..200 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let n = 1 + (state % 4) as usize;
+            let ones: Vec<usize> = (0..(1usize << n)).filter(|i| (state >> (8 + i)) & 1 == 1).collect();
+            let names: Vec<String> = (0..n).map(|i| format!("x{}", i)).collect();
+            let func = BooleanFunction::new(names, ones.iter().map(|&i| bits_of(i, n)).collect());
+
+            for kind in [GateKind::Nand, GateKind::Nor] {
+                let net = func.circuit(kind);
+                assert!(net.gates.iter().all(|g| g.kind == kind));
+                for idx in 0..(1usize << n) {
+                    assert_eq!(simulate(&net, &bits_of(idx, n)), ones.contains(&idx), "{} pentru {:?}, linia {}", kind.name(), ones, idx);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inverters_are_shared() {
+        // !a!b + !ac: inversorul lui a e construit o singură dată
+        let names: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let cover = vec![Term { bits: vec![0, 0, -1], used: false }, Term { bits: vec![0, -1, 1], used: false }];
+        let net = Netlist::nand_from_sop(&names, &cover);
+        assert_eq!(net.inverter_count(), 2);
+        assert_eq!(net.gates.len(), 5);
+        assert_eq!(net.max_fan_in(), 2);
+    }
+}
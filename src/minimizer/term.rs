@@ -0,0 +1,90 @@
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Term {
+    pub bits: Vec<i8>,
+    pub used: bool,
+}
+
+impl Term {
+    pub fn new(raw_bits: &[u8]) -> Self {
+        Term {
+            bits: raw_bits.iter().map(|&b| b as i8).collect(),
+            used: false,
+        }
+    }
+
+    pub fn from_index(idx: usize, num_vars: usize) -> Self {
+        Term::new(&super::bits_of(idx, num_vars))
+    }
+
+    // Produsul de literali (ex: "!ab" pentru bits = [0, 1])
+    pub fn to_string(&self, var_names: &[String]) -> String {
+        let mut s = String::new();
+        let mut first = true;
+        for (i, &b) in self.bits.iter().enumerate() {
+            if b != -1 {
+                if !first { s.push_str(""); }
+                if b == 0 { s.push('!'); }
+                s.push_str(&var_names[i]);
+                first = false;
+            }
+        }
+        if s.is_empty() { "1 (TRUE)".to_string() } else { s }
+    }
+
+    // Clauza (suma) care se anulează exact pe cubul de zerouri descris de termen:
+    // un bit 0 devine literalul `x`, un bit 1 devine `!x` (ex: "(a + !b)").
+    pub fn to_clause_string(&self, var_names: &[String]) -> String {
+        let literals: Vec<String> = self.bits.iter().enumerate()
+            .filter(|(_, &b)| b != -1)
+            .map(|(i, &b)| if b == 1 { format!("!{}", var_names[i]) } else { var_names[i].clone() })
+            .collect();
+        if literals.is_empty() { "0 (FALSE)".to_string() } else { format!("({})", literals.join(" + ")) }
+    }
+
+    pub fn ones_count(&self) -> usize {
+        self.bits.iter().filter(|&&b| b == 1).count()
+    }
+
+    // Indicii mintermilor acoperiți de cub (ex: [1, 3, 5, 7] pentru "--1")
+    pub fn indices(&self) -> Vec<usize> {
+        let mut v = super::pla::expand_cube(&self.bits);
+        v.sort();
+        v
+    }
+
+    // Cubul ca șir de biți, cu '-' pentru variabilele eliminate (ex: "0-1")
+    pub fn bit_string(&self) -> String {
+        self.bits.iter().map(|&b| match b { 0 => '0', 1 => '1', _ => '-' }).collect()
+    }
+
+    pub fn literal_count(&self) -> usize {
+        self.bits.iter().filter(|&&b| b != -1).count()
+    }
+
+    // Verifică dacă linia `idx` din tabelul de adevăr face parte din cubul termenului
+    pub fn covers(&self, idx: usize) -> bool {
+        let n = self.bits.len();
+        self.bits.iter().enumerate().all(|(i, &b)| {
+            b == -1 || ((idx >> (n - 1 - i)) & 1) as i8 == b
+        })
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn combine(&self, other: &Term) -> Option<Term> {
+        let mut diff_count = 0;
+        let mut new_bits = self.bits.clone();
+
+        for i in 0..self.bits.len() {
+            if self.bits[i] != other.bits[i] {
+                diff_count += 1;
+                new_bits[i] = -1;
+            }
+        }
+
+        if diff_count == 1 {
+            Some(Term { bits: new_bits, used: false })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,476 @@
+use colored::*;
+
+use super::term::Term;
+use super::BooleanFunction;
+
+// Diagramele sunt desenate pentru cel mult atâtea variabile (16 sub-diagrame de 4x4)
+pub const MAX_MAP_VARS: usize = 8;
+
+const GROUP_COLORS: [Color; 12] = [
+    Color::Red, Color::Green, Color::Blue, Color::Magenta, Color::Cyan, Color::Yellow,
+    Color::BrightRed, Color::BrightGreen, Color::BrightBlue, Color::BrightMagenta, Color::BrightCyan, Color::BrightYellow,
+];
+
+const SVG_COLORS: [&str; 12] = [
+    "#e6194b", "#3cb44b", "#4363d8", "#911eb4", "#42d4f4", "#f58231",
+    "#f032e6", "#bfef45", "#000075", "#9a6324", "#469990", "#808000",
+];
+
+// Un implicant desenat pe diagramă: simbolul și culoarea lui, plus cubul acoperit
+struct Group<'a> {
+    symbol: char,
+    color: Color,
+    term: &'a Term,
+}
+
+// Împărțirea variabilelor pe diagramă: variabilele sub-diagramelor (cele mai semnificative),
+// apoi cele de pe rânduri și cele de pe coloane. Fiecare listă conține pozițiile variabilelor.
+pub struct MapLayout {
+    pub sub_vars: Vec<usize>,
+    pub row_vars: Vec<usize>,
+    pub col_vars: Vec<usize>,
+}
+
+impl MapLayout {
+    pub fn new(num_vars: usize) -> Self {
+        let (sub, rest) = if num_vars > 5 { (num_vars - 4, 4) } else { (0, num_vars) };
+        let rows = rest / 2;
+        MapLayout {
+            sub_vars: (0..sub).collect(),
+            row_vars: (sub..sub + rows).collect(),
+            col_vars: (sub + rows..num_vars).collect(),
+        }
+    }
+
+    // Indexul în tabelul de adevăr pentru valorile (în cod Gray) ale sub-diagramei, rândului și coloanei
+    pub fn index(&self, num_vars: usize, sub: usize, row: usize, col: usize) -> usize {
+        let mut idx = 0;
+        for (vars, value) in [(&self.sub_vars, sub), (&self.row_vars, row), (&self.col_vars, col)] {
+            for (j, &v) in vars.iter().enumerate() {
+                let bit = (value >> (vars.len() - 1 - j)) & 1;
+                idx |= bit << (num_vars - 1 - v);
+            }
+        }
+        idx
+    }
+}
+
+// Codul Gray reflectat pe k biți: 00, 01, 11, 10, ...
+pub fn gray(k: usize) -> Vec<usize> {
+    (0..(1usize << k)).map(|i| i ^ (i >> 1)).collect()
+}
+
+fn bits_label(value: usize, k: usize) -> String {
+    (0..k).map(|j| if (value >> (k - 1 - j)) & 1 == 1 { '1' } else { '0' }).collect()
+}
+
+impl BooleanFunction {
+    fn literal_label(&self, vars: &[usize], value: usize) -> String {
+        vars.iter().enumerate()
+            .map(|(j, &v)| {
+                let bit = (value >> (vars.len() - 1 - j)) & 1;
+                if bit == 1 { self.var_names[v].clone() } else { format!("!{}", self.var_names[v]) }
+            })
+            .collect()
+    }
+
+    fn cell_value(&self, idx: usize) -> &'static str {
+        if self.minterm_indices.contains(&idx) {
+            "1"
+        } else if self.dont_care_indices.contains(&idx) {
+            "X"
+        } else {
+            "0"
+        }
+    }
+
+    pub fn print_veitch(&self) {
+        self.render_veitch(&[]);
+    }
+
+    // Diagrama cu acoperirea aleasă: fiecare celulă arată simbolurile implicanților care o acoperă
+    // (inclusiv grupurile care trec peste margini), urmată de legendă.
+    pub fn print_veitch_groups(&self, cover: &[Term], labels: &[String]) {
+        if !(1..=MAX_MAP_VARS).contains(&self.var_names.len()) || cover.is_empty() {
+            return;
+        }
+
+        let groups: Vec<Group> = cover.iter().enumerate()
+            .map(|(i, term)| Group {
+                symbol: group_symbol(i),
+                color: GROUP_COLORS[i % GROUP_COLORS.len()],
+                term,
+            })
+            .collect();
+
+        self.render_veitch(&groups);
+
+        println!("Legendă:");
+        for (g, label) in groups.iter().zip(labels) {
+            println!("  {} = {}", g.symbol.to_string().color(g.color).bold(), label.color(g.color));
+        }
+    }
+
+    fn render_veitch(&self, groups: &[Group]) {
+        let n = self.var_names.len();
+        if !(1..=MAX_MAP_VARS).contains(&n) {
+            println!("Diagramele ASCII sunt suportate doar pentru 1-{} variabile.", MAX_MAP_VARS);
+            return;
+        }
+
+        let layout = MapLayout::new(n);
+        let names = |vars: &[usize]| vars.iter().map(|&v| self.var_names[v].as_str()).collect::<Vec<_>>().join("");
+
+        println!("\n=== DIAGRAMA VEITCH ({}) ===", self.var_names.join(", "));
+        if layout.row_vars.is_empty() {
+            println!("Coloane: {}", names(&layout.col_vars));
+        } else {
+            println!("Rânduri: {} | Coloane: {}", names(&layout.row_vars), names(&layout.col_vars));
+        }
+
+        for sub in gray(layout.sub_vars.len()) {
+            if !layout.sub_vars.is_empty() {
+                println!("\n--- {} = {} ({}) ---",
+                    names(&layout.sub_vars),
+                    bits_label(sub, layout.sub_vars.len()),
+                    self.literal_label(&layout.sub_vars, sub));
+            }
+            self.render_submap(&layout, sub, groups);
+        }
+    }
+
+    fn render_submap(&self, layout: &MapLayout, sub: usize, groups: &[Group]) {
+        let n = self.var_names.len();
+        let cols = gray(layout.col_vars.len());
+        let rows = gray(layout.row_vars.len());
+
+        let cell_width = layout.col_vars.len().max(3);
+        let row_labels: Vec<String> = rows.iter().map(|&r| self.literal_label(&layout.row_vars, r)).collect();
+        let label_width = row_labels.iter().map(|l| l.chars().count()).max().unwrap_or(0).max(4);
+        let separator = format!("{} +{}", " ".repeat(label_width), format!("{}+", "-".repeat(cell_width)).repeat(cols.len()));
+
+        let header: String = cols.iter()
+            .map(|&c| format!("{:^w$} ", bits_label(c, layout.col_vars.len()), w = cell_width))
+            .collect();
+        println!("{}  {}", " ".repeat(label_width), header);
+        println!("{}", separator);
+
+        for (&r, label) in rows.iter().zip(&row_labels) {
+            print!("{:>w$} |", label, w = label_width);
+            for &c in &cols {
+                let idx = layout.index(n, sub, r, c);
+                print!("{}|", self.get_cell(idx, groups, cell_width));
+            }
+            println!("\n{}", separator);
+        }
+    }
+
+    fn get_cell(&self, idx: usize, groups: &[Group], width: usize) -> String {
+        let covering: Vec<&Group> = groups.iter().filter(|g| g.term.covers(idx)).collect();
+        if !covering.is_empty() {
+            // Cel mult `width` coloane pe celulă; grupurile care nu mai încap sunt marcate cu '+'
+            let mut cell = String::new();
+            let mut used = 0;
+            for (k, g) in covering.iter().enumerate() {
+                let needed = symbol_width(g.symbol) + if k + 1 < covering.len() { 1 } else { 0 };
+                if used + needed > width {
+                    cell.push_str(&"+".bold().to_string());
+                    used += 1;
+                    break;
+                }
+                cell.push_str(&g.symbol.to_string().color(g.color).bold().to_string());
+                used += symbol_width(g.symbol);
+            }
+            let pad = width - used;
+            return format!("{}{}{}", " ".repeat(pad.div_ceil(2)), cell, " ".repeat(pad / 2));
+        }
+
+        let text = format!("{:^w$}", self.cell_value(idx), w = width);
+        match self.cell_value(idx) {
+            "1" => text.green().bold().to_string(),
+            "X" => text.yellow().to_string(),
+            _ => text.dimmed().to_string(),
+        }
+    }
+
+    // Diagrama ca imagine SVG: valorile celulelor, etichetele Gray și implicanții acoperirii
+    // desenați ca chenare colorate (câte unul pe celulă, deci și grupurile care trec peste margini).
+    pub fn to_svg(&self, cover: &[Term], labels: &[String]) -> Result<String, String> {
+        let n = self.var_names.len();
+        if !(1..=MAX_MAP_VARS).contains(&n) {
+            return Err(format!("Diagramele Veitch sunt suportate doar pentru 1-{} variabile.", MAX_MAP_VARS));
+        }
+        let layout = MapLayout::new(n);
+        let rows = gray(layout.row_vars.len());
+        let cols = gray(layout.col_vars.len());
+        let subs = gray(layout.sub_vars.len());
+
+        let cell = 44;
+        let margin_left = 20 + 10 * layout.row_vars.len().max(1) * 2;
+        let margin_top = 60;
+        let map_w = margin_left + cols.len() * cell + 30;
+        let map_h = margin_top + rows.len() * cell + 20;
+        let per_line = if subs.len() > 1 { 2 } else { 1 };
+        let width = map_w * per_line + 20;
+        let map_lines = subs.len().div_ceil(per_line);
+        let height = map_h * map_lines + 30 + 20 * cover.len();
+
+        let names = |vars: &[usize]| vars.iter().map(|&v| self.var_names[v].as_str()).collect::<Vec<_>>().join("");
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"14\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+            width, height
+        );
+
+        for (s, &sub) in subs.iter().enumerate() {
+            let ox = 10 + (s % per_line) * map_w;
+            let oy = 10 + (s / per_line) * map_h;
+            let gx = ox + margin_left;
+            let gy = oy + margin_top;
+
+            let title = if layout.sub_vars.is_empty() {
+                format!("{} \\ {}", names(&layout.row_vars), names(&layout.col_vars))
+            } else {
+                format!("{} = {}   ({} \\ {})", names(&layout.sub_vars), bits_label(sub, layout.sub_vars.len()),
+                    names(&layout.row_vars), names(&layout.col_vars))
+            };
+            svg.push_str(&format!("<text x=\"{}\" y=\"{}\" font-weight=\"bold\">{}</text>\n", ox, oy + 16, xml_escape(&title)));
+
+            for (j, &c) in cols.iter().enumerate() {
+                svg.push_str(&format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+                    gx + j * cell + cell / 2, gy - 8, bits_label(c, layout.col_vars.len())));
+            }
+            for (i, &r) in rows.iter().enumerate() {
+                if !layout.row_vars.is_empty() {
+                    svg.push_str(&format!("<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\n",
+                        gx - 8, gy + i * cell + cell / 2 + 5, bits_label(r, layout.row_vars.len())));
+                }
+                for (j, &c) in cols.iter().enumerate() {
+                    let idx = layout.index(n, sub, r, c);
+                    let (x, y) = (gx + j * cell, gy + i * cell);
+                    svg.push_str(&format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"black\"/>\n", x, y, cell, cell));
+                    svg.push_str(&format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n", x + cell / 2, y + cell / 2 + 5, self.cell_value(idx)));
+
+                    for k in (0..cover.len()).filter(|&k| cover[k].covers(idx)) {
+                        let inset = 3 + 3 * (k % 5);
+                        svg.push_str(&format!(
+                            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"6\" fill=\"{}\" fill-opacity=\"0.12\" stroke=\"{}\" stroke-width=\"2\"/>\n",
+                            x + inset, y + inset, cell - 2 * inset, cell - 2 * inset,
+                            SVG_COLORS[k % SVG_COLORS.len()], SVG_COLORS[k % SVG_COLORS.len()]
+                        ));
+                    }
+                }
+            }
+        }
+
+        let legend_y = 10 + map_lines * map_h + 10;
+        for (k, label) in labels.iter().enumerate() {
+            svg.push_str(&format!("<text x=\"10\" y=\"{}\" fill=\"{}\">{} = {}</text>\n",
+                legend_y + 20 * k, SVG_COLORS[k % SVG_COLORS.len()], group_symbol(k), xml_escape(label)));
+        }
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+
+    // Diagrama pentru pachetul LaTeX `karnaugh-map` (cel mult 6 variabile: 4x4, cu adâncime 1, 2 sau 4).
+    // Numerotarea celulelor din pachet coincide cu indicii noștri: coloanele sunt biții cei mai puțin
+    // semnificativi, apoi rândurile, apoi sub-diagramele.
+    pub fn to_latex(&self, cover: &[Term]) -> Result<String, String> {
+        let n = self.var_names.len();
+        if !(1..=6).contains(&n) {
+            return Err("Pachetul karnaugh-map suportă doar 1-6 variabile.".to_string());
+        }
+
+        let depth_vars = n.saturating_sub(4);
+        let row_vars = (n - depth_vars) / 2;
+        let col_vars = n - depth_vars - row_vars;
+        let label = |range: std::ops::Range<usize>| {
+            format!("${}$", self.var_names[range].join(""))
+        };
+
+        let mut tex = String::new();
+        tex.push_str("% \\usepackage{karnaugh-map}\n");
+        tex.push_str(&format!("\\begin{{karnaugh-map}}[{}][{}][{}][{}][{}]",
+            1 << col_vars, 1 << row_vars, 1 << depth_vars,
+            label(depth_vars + row_vars..n),
+            label(depth_vars..depth_vars + row_vars)));
+        if depth_vars > 0 {
+            tex.push_str(&format!("[{}]", label(0..depth_vars)));
+        }
+        tex.push('\n');
+
+        let join = |v: Vec<usize>| v.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(",");
+        tex.push_str(&format!("    \\minterms{{{}}}\n", join(self.minterm_indices())));
+        tex.push_str(&format!("    \\maxterms{{{}}}\n", join(self.maxterm_indices())));
+        if !self.dont_care_indices.is_empty() {
+            tex.push_str(&format!("    \\indeterminants{{{}}}\n", join(self.dont_care_indices())));
+        }
+
+        let rows = gray(row_vars);
+        let cols = gray(col_vars);
+        let index = |d: usize, r: usize, c: usize| (d << (row_vars + col_vars)) | (r << col_vars) | c;
+
+        for (k, t) in cover.iter().enumerate() {
+            let covered: Vec<usize> = (0..(1usize << depth_vars)).filter(|&d| {
+                rows.iter().any(|&r| cols.iter().any(|&c| t.covers(index(d, r, c))))
+            }).collect();
+            if covered.is_empty() { continue; }
+            let d = covered[0];
+            let submaps = if depth_vars > 0 { format!("[{}]", join(covered.clone())) } else { String::new() };
+
+            let row_pos: Vec<usize> = (0..rows.len()).filter(|&i| cols.iter().any(|&c| t.covers(index(d, rows[i], c)))).collect();
+            let col_pos: Vec<usize> = (0..cols.len()).filter(|&j| rows.iter().any(|&r| t.covers(index(d, r, cols[j])))).collect();
+            let (row_runs, col_runs) = (runs(&row_pos), runs(&col_pos));
+            let cell = |i: usize, j: usize| index(d, rows[i], cols[j]);
+
+            let command = match (row_runs.as_slice(), col_runs.as_slice()) {
+                ([(r0, r1)], [(c0, c1)]) => format!("\\implicant{{{}}}{{{}}}", cell(*r0, *c0), cell(*r1, *c1)),
+                ([(r0, r1)], [(a0, a1), (b0, b1)]) => format!("\\implicantedge{{{}}}{{{}}}{{{}}}{{{}}}",
+                    cell(*r0, *a0), cell(*r1, *a1), cell(*r0, *b0), cell(*r1, *b1)),
+                ([(a0, a1), (b0, b1)], [(c0, c1)]) => format!("\\implicantedge{{{}}}{{{}}}{{{}}}{{{}}}",
+                    cell(*a0, *c0), cell(*a1, *c1), cell(*b0, *c0), cell(*b1, *c1)),
+                _ => "\\implicantcorner".to_string(),
+            };
+            tex.push_str(&format!("    {}{} % {} = {}\n", command, submaps, group_symbol(k), t.to_string(&self.var_names)));
+        }
+
+        tex.push_str("\\end{karnaugh-map}\n");
+        Ok(tex)
+    }
+}
+
+// Pozițiile consecutive grupate în intervale; un grup care trece peste margine are două intervale
+fn runs(positions: &[usize]) -> Vec<(usize, usize)> {
+    let mut result: Vec<(usize, usize)> = Vec::new();
+    for &p in positions {
+        match result.last_mut() {
+            Some((_, end)) if *end + 1 == p => *end = p,
+            _ => result.push((p, p)),
+        }
+    }
+    result
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// Simbolul grupului: cifre încercuite ①..㊿, ca să nu se confunde cu numele variabilelor
+fn group_symbol(i: usize) -> char {
+    let symbols: Vec<char> = ('①'..='⑳').chain('㉑'..='㉟').chain('㊱'..='㊿').collect();
+    symbols[i % symbols.len()]
+}
+
+// Coloanele ocupate în terminal: ㉑..㊿ sunt caractere late (două coloane), ①..⑳ nu
+fn symbol_width(symbol: char) -> usize {
+    if symbol >= '㉑' { 2 } else { 1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::formula::Formula;
+
+    #[test]
+    fn group_symbols_are_distinct_from_variable_names() {
+        let symbols: Vec<char> = (0..50).map(group_symbol).collect();
+        assert!(symbols.iter().all(|c| !c.is_ascii_alphanumeric()));
+        let unique: HashSet<char> = symbols.iter().copied().collect();
+        assert_eq!(unique.len(), 50);
+    }
+
+    // Lățimea afișată a unei celule, fără codurile de culoare
+    fn display_width(cell: &str) -> usize {
+        let mut width = 0;
+        let mut in_escape = false;
+        for c in cell.chars() {
+            match c {
+                '\u{1b}' => in_escape = true,
+                'm' if in_escape => in_escape = false,
+                _ if in_escape => {},
+                _ => width += symbol_width(c),
+            }
+        }
+        width
+    }
+
+    #[test]
+    fn wide_symbols_keep_cells_aligned() {
+        let func = BooleanFunction::new(vec!["a".to_string()], vec![vec![1]]);
+        let term = Term::new(&[1]);
+        let groups: Vec<Group> = (18..22)
+            .map(|i| Group { symbol: group_symbol(i), color: Color::Red, term: &term })
+            .collect();
+        // ⑲ ⑳ ㉑ ㉒ pe celule de 3 și 4 coloane: fiecare celulă are exact lățimea cerută
+        for count in 1..=groups.len() {
+            for offset in 0..=groups.len() - count {
+                for width in [3, 4] {
+                    let cell = func.get_cell(1, &groups[offset..offset + count], width);
+                    assert_eq!(display_width(&cell), width, "{:?}", cell);
+                }
+            }
+        }
+        let cell = func.get_cell(1, &groups[2..], 3);
+        assert!(cell.contains('㉑') && cell.contains('+') && !cell.contains('㉒'));
+    }
+
+    #[test]
+    fn gray_code_changes_one_bit() {
+        let code = gray(3);
+        assert_eq!(code, vec![0, 1, 3, 2, 6, 7, 5, 4]);
+        assert!(code.windows(2).all(|w| (w[0] ^ w[1]).count_ones() == 1));
+    }
+
+    #[test]
+    fn layout_covers_every_row_once() {
+        // Fiecare celulă din toate sub-diagramele corespunde unei linii distincte din tabel
+        for n in 1..=MAX_MAP_VARS {
+            let layout = MapLayout::new(n);
+            assert!(layout.row_vars.len() <= 2 && layout.col_vars.len() <= 3);
+            let mut seen = vec![false; 1 << n];
+            for sub in gray(layout.sub_vars.len()) {
+                for r in gray(layout.row_vars.len()) {
+                    for c in gray(layout.col_vars.len()) {
+                        let idx = layout.index(n, sub, r, c);
+                        assert!(!seen[idx], "{} variabile: linia {} apare de două ori", n, idx);
+                        seen[idx] = true;
+                    }
+                }
+            }
+            assert!(seen.iter().all(|&s| s));
+        }
+    }
+
+    #[test]
+    fn latex_is_limited_to_six_variables() {
+        let names: Vec<String> = (0..7).map(|i| format!("x{}", i)).collect();
+        let func = BooleanFunction::new(names, vec![vec![1; 7]]);
+        assert!(func.to_latex(&func.sop_cover()).is_err());
+    }
+
+    #[test]
+    fn svg_is_limited_to_map_size() {
+        let n = MAX_MAP_VARS + 1;
+        let names: Vec<String> = (0..n).map(|i| format!("x{}", i)).collect();
+        let func = BooleanFunction::new(names, vec![vec![1; n]]);
+        let cover = func.sop_cover();
+        assert!(func.to_svg(&cover, &["x".to_string()]).is_err());
+        let empty = BooleanFunction::new(Vec::new(), Vec::new());
+        assert!(empty.to_svg(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn exports_label_groups_with_symbols() {
+        let f = Formula::or(Formula::and(Formula::var("A"), Formula::var("B")), Formula::and(Formula::not(Formula::var("A")), Formula::var("C")));
+        let func = BooleanFunction::from_formula(&f);
+        let cover = func.sop_cover();
+        let labels: Vec<String> = cover.iter().map(|t| t.to_string(&func.var_names)).collect();
+
+        let svg = func.to_svg(&cover, &labels).unwrap();
+        assert!(svg.contains("① = !AC") && svg.contains("② = AB"));
+        let tex = func.to_latex(&cover).unwrap();
+        assert!(tex.contains("% ① = !AC") && tex.contains("% ② = AB"));
+    }
+}
@@ -0,0 +1,390 @@
+use colored::*;
+
+use crate::cnf::Cnf;
+use crate::formula::Formula;
+
+// Peste acest număr estimat de clauze, distributivitatea nu mai e aplicată (se recomandă Tseitin)
+pub const DISTRIBUTION_LIMIT: u128 = 4096;
+
+// Un pas de rescriere: legea aplicată și formula rezultată
+pub struct Step {
+    pub law: &'static str,
+    pub result: Formula,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalForm {
+    Nnf,
+    Cnf,
+    Dnf,
+}
+
+impl NormalForm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NormalForm::Nnf => "FNN (forma normală negată)",
+            NormalForm::Cnf => "FNC (forma normală conjunctivă)",
+            NormalForm::Dnf => "FND (forma normală disjunctivă)",
+        }
+    }
+}
+
+pub fn eliminate_implications(f: &Formula) -> Formula {
+    match f {
+        Formula::Var(_) => f.clone(),
+        Formula::Not(inner) => Formula::not(eliminate_implications(inner)),
+        Formula::And(l, r) => Formula::and(eliminate_implications(l), eliminate_implications(r)),
+        Formula::Or(l, r) => Formula::or(eliminate_implications(l), eliminate_implications(r)),
+        Formula::Implies(l, r) => Formula::or(Formula::not(eliminate_implications(l)), eliminate_implications(r)),
+    }
+}
+
+// Negațiile coborâte până la variabile (De Morgan, dubla negație)
+pub fn to_nnf(f: &Formula) -> Formula {
+    nnf(&eliminate_implications(f), false)
+}
+
+fn nnf(f: &Formula, negated: bool) -> Formula {
+    match f {
+        Formula::Var(_) => if negated { Formula::not(f.clone()) } else { f.clone() },
+        Formula::Not(inner) => nnf(inner, !negated),
+        Formula::And(l, r) if negated => Formula::or(nnf(l, true), nnf(r, true)),
+        Formula::Or(l, r) if negated => Formula::and(nnf(l, true), nnf(r, true)),
+        Formula::And(l, r) => Formula::and(nnf(l, false), nnf(r, false)),
+        Formula::Or(l, r) => Formula::or(nnf(l, false), nnf(r, false)),
+        Formula::Implies(l, r) => nnf(&Formula::or(Formula::not((**l).clone()), (**r).clone()), negated),
+    }
+}
+
+pub fn to_cnf(f: &Formula) -> Formula {
+    distribute(&to_nnf(f), true)
+}
+
+pub fn to_dnf(f: &Formula) -> Formula {
+    distribute(&to_nnf(f), false)
+}
+
+// Distributivitatea pe o formulă în FNN: `outer_and` cere FNC (∧ de ∨), altfel FND
+fn distribute(f: &Formula, outer_and: bool) -> Formula {
+    match f {
+        Formula::And(l, r) if outer_and => Formula::and(distribute(l, true), distribute(r, true)),
+        Formula::Or(l, r) if !outer_and => Formula::or(distribute(l, false), distribute(r, false)),
+        Formula::And(l, r) | Formula::Or(l, r) => {
+            let (a, b) = (distribute(l, outer_and), distribute(r, outer_and));
+            spread(&a, &b, outer_and)
+        },
+        _ => f.clone(),
+    }
+}
+
+// Combină două forme deja normalizate: (a1 ∧ a2) ∨ b = (a1 ∨ b) ∧ (a2 ∨ b), și dual
+fn spread(a: &Formula, b: &Formula, outer_and: bool) -> Formula {
+    let join = |x: Formula, y: Formula| if outer_and { Formula::and(x, y) } else { Formula::or(x, y) };
+    let inner = |x: Formula, y: Formula| if outer_and { Formula::or(x, y) } else { Formula::and(x, y) };
+    match (a, b) {
+        (Formula::And(a1, a2), _) if outer_and => join(spread(a1, b, outer_and), spread(a2, b, outer_and)),
+        (Formula::Or(a1, a2), _) if !outer_and => join(spread(a1, b, outer_and), spread(a2, b, outer_and)),
+        (_, Formula::And(b1, b2)) if outer_and => join(spread(a, b1, outer_and), spread(a, b2, outer_and)),
+        (_, Formula::Or(b1, b2)) if !outer_and => join(spread(a, b1, outer_and), spread(a, b2, outer_and)),
+        _ => inner(a.clone(), b.clone()),
+    }
+}
+
+// Numărul de clauze (FNC) sau de termeni (FND) rezultat prin distributivitate, calculat fără a o aplica
+pub fn estimated_size(f: &Formula, cnf: bool) -> u128 {
+    let f = to_nnf(f);
+    count(&f, cnf)
+}
+
+fn count(f: &Formula, cnf: bool) -> u128 {
+    match f {
+        Formula::And(l, r) if cnf => count(l, cnf).saturating_add(count(r, cnf)),
+        Formula::Or(l, r) if !cnf => count(l, cnf).saturating_add(count(r, cnf)),
+        Formula::And(l, r) | Formula::Or(l, r) => count(l, cnf).saturating_mul(count(r, cnf)),
+        _ => 1,
+    }
+}
+
+pub fn convert(f: &Formula, form: NormalForm) -> Formula {
+    match form {
+        NormalForm::Nnf => to_nnf(f),
+        NormalForm::Cnf => to_cnf(f),
+        NormalForm::Dnf => to_dnf(f),
+    }
+}
+
+// O lege de rescriere: formula rescrisă și numele legii, dacă se aplică pe nod
+type Law = fn(&Formula) -> Option<(Formula, &'static str)>;
+
+fn implication_law(f: &Formula) -> Option<(Formula, &'static str)> {
+    match f {
+        Formula::Implies(l, r) => Some((Formula::or(Formula::not((**l).clone()), (**r).clone()), "Eliminarea implicației: A → B ≡ ¬A ∨ B")),
+        _ => None,
+    }
+}
+
+fn negation_law(f: &Formula) -> Option<(Formula, &'static str)> {
+    let Formula::Not(inner) = f else { return None };
+    match &**inner {
+        Formula::Not(a) => Some(((**a).clone(), "Dubla negație: ¬¬A ≡ A")),
+        Formula::And(a, b) => Some((Formula::or(Formula::not((**a).clone()), Formula::not((**b).clone())), "De Morgan: ¬(A ∧ B) ≡ ¬A ∨ ¬B")),
+        Formula::Or(a, b) => Some((Formula::and(Formula::not((**a).clone()), Formula::not((**b).clone())), "De Morgan: ¬(A ∨ B) ≡ ¬A ∧ ¬B")),
+        _ => None,
+    }
+}
+
+fn cnf_law(f: &Formula) -> Option<(Formula, &'static str)> {
+    let Formula::Or(l, r) = f else { return None };
+    match (&**l, &**r) {
+        (_, Formula::And(b, c)) => Some((
+            Formula::and(Formula::or((**l).clone(), (**b).clone()), Formula::or((**l).clone(), (**c).clone())),
+            "Distributivitate: A ∨ (B ∧ C) ≡ (A ∨ B) ∧ (A ∨ C)",
+        )),
+        (Formula::And(a, b), _) => Some((
+            Formula::and(Formula::or((**a).clone(), (**r).clone()), Formula::or((**b).clone(), (**r).clone())),
+            "Distributivitate: (A ∧ B) ∨ C ≡ (A ∨ C) ∧ (B ∨ C)",
+        )),
+        _ => None,
+    }
+}
+
+fn dnf_law(f: &Formula) -> Option<(Formula, &'static str)> {
+    let Formula::And(l, r) = f else { return None };
+    match (&**l, &**r) {
+        (_, Formula::Or(b, c)) => Some((
+            Formula::or(Formula::and((**l).clone(), (**b).clone()), Formula::and((**l).clone(), (**c).clone())),
+            "Distributivitate: A ∧ (B ∨ C) ≡ (A ∧ B) ∨ (A ∧ C)",
+        )),
+        (Formula::Or(a, b), _) => Some((
+            Formula::or(Formula::and((**a).clone(), (**r).clone()), Formula::and((**b).clone(), (**r).clone())),
+            "Distributivitate: (A ∨ B) ∧ C ≡ (A ∧ C) ∨ (B ∧ C)",
+        )),
+        _ => None,
+    }
+}
+
+// O singură rescriere, pe primul nod (de sus în jos, de la stânga) unde se aplică legea
+fn rewrite_once(f: &Formula, law: Law) -> Option<(Formula, &'static str)> {
+    if let Some(r) = law(f) {
+        return Some(r);
+    }
+    match f {
+        Formula::Var(_) => None,
+        Formula::Not(inner) => rewrite_once(inner, law).map(|(g, name)| (Formula::not(g), name)),
+        Formula::And(l, r) | Formula::Or(l, r) | Formula::Implies(l, r) => {
+            let rebuild = |a: Formula, b: Formula| match f {
+                Formula::And(_, _) => Formula::and(a, b),
+                Formula::Or(_, _) => Formula::or(a, b),
+                _ => Formula::implies(a, b),
+            };
+            if let Some((g, name)) = rewrite_once(l, law) {
+                return Some((rebuild(g, (**r).clone()), name));
+            }
+            rewrite_once(r, law).map(|(g, name)| (rebuild((**l).clone(), g), name))
+        },
+    }
+}
+
+// Conversia pas cu pas: implicațiile, apoi negațiile, apoi (pentru FNC/FND) distributivitatea
+pub fn trace(f: &Formula, form: NormalForm) -> Vec<Step> {
+    let mut laws: Vec<Law> = vec![implication_law, negation_law];
+    match form {
+        NormalForm::Nnf => {},
+        NormalForm::Cnf => laws.push(cnf_law),
+        NormalForm::Dnf => laws.push(dnf_law),
+    }
+
+    let mut steps = Vec::new();
+    let mut current = f.clone();
+    for law in laws {
+        while let Some((next, name)) = rewrite_once(&current, law) {
+            steps.push(Step { law: name, result: next.clone() });
+            current = next;
+        }
+    }
+    steps
+}
+
+pub fn print_trace(f: &Formula, form: NormalForm) {
+    println!("\n{}", format!("=== {} — PAS CU PAS ===", form.name()).purple().bold());
+    println!("    {}", f.to_string().yellow());
+    let steps = trace(f, form);
+    for (i, step) in steps.iter().enumerate() {
+        println!("{:>3}. {}", i + 1, step.result);
+        println!("     {}", format!("[{}]", step.law).dimmed());
+    }
+    if steps.is_empty() {
+        println!("{}", "Formula este deja în forma cerută.".green());
+    }
+}
+
+// Formele cerute, cu mulțimea de clauze pentru FNC. Dacă distributivitatea ar exploda,
+// FNC e înlocuită de codificarea Tseitin (afișată oricum când `tseitin` e cerut).
+pub fn print_forms(f: &Formula, forms: &[NormalForm], steps: bool, tseitin: bool) {
+    if !forms.is_empty() {
+        println!("\n=== FORME NORMALE ===");
+    }
+    let mut exploded = false;
+    for &form in forms {
+        let label = match form {
+            NormalForm::Nnf => "FNN",
+            NormalForm::Cnf => "FNC",
+            NormalForm::Dnf => "FND",
+        };
+        if form != NormalForm::Nnf {
+            let size = estimated_size(f, form == NormalForm::Cnf);
+            if size > DISTRIBUTION_LIMIT {
+                println!("{}: {}", label.bold(), format!("distributivitatea ar produce {} {} (limita e {})",
+                    size, if form == NormalForm::Cnf { "clauze" } else { "termeni" }, DISTRIBUTION_LIMIT).red());
+                exploded |= form == NormalForm::Cnf;
+                continue;
+            }
+        }
+
+        if steps {
+            print_trace(f, form);
+        }
+        let result = convert(f, form);
+        println!("{}: {}", label.bold(), result.to_string().cyan().bold());
+        if form == NormalForm::Cnf {
+            if let Ok(mut cnf) = Cnf::from_cnf_formula(&result) {
+                cnf.simplify();
+                println!("     clauze: {}  {}", cnf.to_set_string(), format!("({} clauze)", cnf.clauses.len()).dimmed());
+            }
+        }
+    }
+    if exploded || tseitin {
+        print_tseitin(f);
+    }
+}
+
+pub fn print_tseitin(f: &Formula) {
+    let (cnf, definitions) = Cnf::tseitin_with_definitions(f);
+    let original = f.variables().len();
+    println!("\n=== CODIFICAREA TSEITIN (echisatisfiabilă) ===");
+    for (name, definition) in &definitions {
+        println!("  {} ≡ {}", name.yellow(), definition);
+    }
+    println!("Clauze: {}", cnf.to_set_string());
+    println!("{}", format!("{} variabile ({} auxiliare), {} clauze", cnf.num_vars(), cnf.num_vars() - original, cnf.clauses.len()).dimmed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::Valuation;
+    use crate::parser::parse_formula;
+
+    fn valuations(vars: &[String]) -> Vec<Valuation> {
+        let n = vars.len();
+        (0..1usize << n)
+            .map(|idx| vars.iter().enumerate().map(|(i, v)| (v.clone(), (idx >> (n - 1 - i)) & 1 == 1)).collect())
+            .collect()
+    }
+
+    fn equivalent(f: &Formula, g: &Formula) -> bool {
+        valuations(&f.variables()).iter().all(|v| f.eval(v) == g.eval(v))
+    }
+
+    fn is_nnf(f: &Formula) -> bool {
+        match f {
+            Formula::Var(_) => true,
+            Formula::Not(inner) => matches!(**inner, Formula::Var(_)),
+            Formula::And(l, r) | Formula::Or(l, r) => is_nnf(l) && is_nnf(r),
+            Formula::Implies(_, _) => false,
+        }
+    }
+
+    // FNC: niciun ∧ sub un ∨ (și dual pentru FND)
+    fn is_flat(f: &Formula, cnf: bool) -> bool {
+        match f {
+            Formula::And(l, r) if cnf => is_flat(l, cnf) && is_flat(r, cnf),
+            Formula::Or(l, r) if !cnf => is_flat(l, cnf) && is_flat(r, cnf),
+            _ => is_clause(f, cnf),
+        }
+    }
+
+    fn is_clause(f: &Formula, cnf: bool) -> bool {
+        match f {
+            Formula::Or(l, r) if cnf => is_clause(l, cnf) && is_clause(r, cnf),
+            Formula::And(l, r) if !cnf => is_clause(l, cnf) && is_clause(r, cnf),
+            _ => f.is_literal(),
+        }
+    }
+
+    const SAMPLES: [&str; 5] = [
+        "!(p -> q) | (r & !p)",
+        "(p | q) & (p -> r) -> !(q & r)",
+        "!!p -> !(q | !r)",
+        "(a & b) | (c & d) | (e & a)",
+        "p",
+    ];
+
+    #[test]
+    fn conversions_keep_the_meaning_and_the_shape() {
+        for text in SAMPLES {
+            let f = parse_formula(text);
+            let nnf = to_nnf(&f);
+            let cnf = to_cnf(&f);
+            let dnf = to_dnf(&f);
+            assert!(is_nnf(&nnf) && equivalent(&f, &nnf), "FNN pentru {}", text);
+            assert!(is_flat(&cnf, true) && equivalent(&f, &cnf), "FNC pentru {}", text);
+            assert!(is_flat(&dnf, false) && equivalent(&f, &dnf), "FND pentru {}", text);
+        }
+    }
+
+    #[test]
+    fn de_morgan_and_double_negation() {
+        assert_eq!(to_nnf(&parse_formula("!(p & !q)")), parse_formula("!p | q"));
+        assert_eq!(to_nnf(&parse_formula("!(p -> q)")), parse_formula("p & !q"));
+        assert_eq!(to_nnf(&parse_formula("!!p")), parse_formula("p"));
+    }
+
+    #[test]
+    fn trace_ends_in_the_normal_form() {
+        for text in SAMPLES {
+            let f = parse_formula(text);
+            for form in [NormalForm::Nnf, NormalForm::Cnf, NormalForm::Dnf] {
+                let steps = trace(&f, form);
+                let last = steps.last().map(|s| s.result.clone()).unwrap_or_else(|| f.clone());
+                assert!(equivalent(&f, &last));
+                assert!(is_nnf(&last));
+                match form {
+                    NormalForm::Cnf => assert!(is_flat(&last, true)),
+                    NormalForm::Dnf => assert!(is_flat(&last, false)),
+                    NormalForm::Nnf => {},
+                }
+            }
+        }
+        let steps = trace(&parse_formula("p -> q"), NormalForm::Nnf);
+        assert_eq!(steps.len(), 1);
+        assert!(steps[0].law.starts_with("Eliminarea implicației"));
+    }
+
+    #[test]
+    fn distribution_size_is_estimated() {
+        // (a1 ∧ b1) ∨ ... ∨ (an ∧ bn) are 2^n clauze în FNC și n termeni în FND
+        let f = parse_formula("(a1 & b1) | (a2 & b2) | (a3 & b3) | (a4 & b4)");
+        assert_eq!(estimated_size(&f, true), 16);
+        assert_eq!(estimated_size(&f, false), 4);
+        let mut cnf = Cnf::from_cnf_formula(&to_cnf(&f)).unwrap();
+        cnf.simplify();
+        assert_eq!(cnf.clauses.len(), 16);
+    }
+
+    #[test]
+    fn tseitin_is_equisatisfiable() {
+        for text in SAMPLES.iter().chain(&["p & !p", "(p -> q) & p & !q"]) {
+            let f = parse_formula(text);
+            let cnf = Cnf::tseitin(&f);
+            let original = f.variables();
+            let models = valuations(&cnf.var_names).into_iter()
+                .filter(|v| cnf.clause_formulas().iter().all(|c| c.eval(v)))
+                .collect::<Vec<_>>();
+            // Fiecare model al formulei se extinde în exact un model al codificării
+            let satisfying = valuations(&original).into_iter().filter(|v| f.eval(v)).count();
+            assert_eq!(models.len(), satisfying, "{}", text);
+            assert!(models.iter().all(|v| f.eval(v)));
+        }
+    }
+}
@@ -3,15 +3,15 @@ use crate::formula::Formula;
 #[derive(Debug, Clone, PartialEq)]
 enum Token {
     Var(String),
-    And,        // &
-    Or,         // |
+    And,        // & sau ∧
+    Or,         // | sau ∨
     Not,        // ! sau ~
-    Implies,    // ->
+    Implies,    // -> sau →
     LParen,     // (
     RParen,     // )
 }
 
-fn tokenize(input: &str) -> Vec<Token> {
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
     let mut tokens = Vec::new();
     let mut chars = input.chars().peekable();
 
@@ -20,16 +20,17 @@ fn tokenize(input: &str) -> Vec<Token> {
             ' ' | '\t' | '\n' => { chars.next(); }
             '(' => { tokens.push(Token::LParen); chars.next(); },
             ')' => { tokens.push(Token::RParen); chars.next(); },
-            '&' | '^' => { tokens.push(Token::And); chars.next(); },
-            '|' | 'v' => { tokens.push(Token::Or); chars.next(); },
+            '&' | '^' | '∧' => { tokens.push(Token::And); chars.next(); },
+            '|' | 'v' | '∨' => { tokens.push(Token::Or); chars.next(); },
             '!' | '~' | '¬' => { tokens.push(Token::Not); chars.next(); },
+            '→' => { tokens.push(Token::Implies); chars.next(); },
             '-' => {
                 chars.next();
                 if let Some('>') = chars.peek() {
                     tokens.push(Token::Implies);
                     chars.next();
                 } else {
-                    panic!("Caracter neașteptat după '-': așteptam '>'");
+                    return Err("Caracter neașteptat după '-': așteptam '>'".to_string());
                 }
             },
             _ if c.is_alphanumeric() => {
@@ -44,10 +45,10 @@ fn tokenize(input: &str) -> Vec<Token> {
                 }
                 tokens.push(Token::Var(name));
             },
-            _ => panic!("Caracter invalid: {}", c),
+            _ => return Err(format!("Caracter invalid: {}", c)),
         }
     }
-    tokens
+    Ok(tokens)
 }
 
 struct Parser {
@@ -68,76 +69,87 @@ impl Parser {
         self.pos += 1;
     }
 
-    fn expect(&mut self, token: Token) {
+    fn expect(&mut self, token: Token) -> Result<(), String> {
         if self.current() == Some(&token) {
             self.advance();
+            Ok(())
         } else {
-            panic!("Eroare de sintaxă: Așteptam {:?}, am găsit {:?}", token, self.current());
+            Err(format!("Eroare de sintaxă: Așteptam {:?}, am găsit {:?}", token, self.current()))
         }
     }
 
-    pub fn parse(&mut self) -> Formula {
-        self.parse_implies()
+    pub fn parse(&mut self) -> Result<Formula, String> {
+        let formula = self.parse_implies()?;
+        // Formula trebuie să consume tot textul (ex: "P Q" sau "P & Q)" sunt respinse)
+        match self.current() {
+            None => Ok(formula),
+            Some(token) => Err(format!("Sintaxă invalidă la poziția {}: {:?} în plus după formulă", self.pos, token)),
+        }
     }
 
-    fn parse_implies(&mut self) -> Formula {
-        let mut left = self.parse_or();
+    fn parse_implies(&mut self) -> Result<Formula, String> {
+        let mut left = self.parse_or()?;
 
         while let Some(Token::Implies) = self.current() {
             self.advance();
-            let right = self.parse_implies();
+            let right = self.parse_implies()?;
             left = Formula::implies(left, right);
         }
-        left
+        Ok(left)
     }
 
-    fn parse_or(&mut self) -> Formula {
-        let mut left = self.parse_and();
+    fn parse_or(&mut self) -> Result<Formula, String> {
+        let mut left = self.parse_and()?;
 
         while let Some(Token::Or) = self.current() {
             self.advance();
-            let right = self.parse_and();
+            let right = self.parse_and()?;
             left = Formula::or(left, right);
         }
-        left
+        Ok(left)
     }
 
-    fn parse_and(&mut self) -> Formula {
-        let mut left = self.parse_unary();
+    fn parse_and(&mut self) -> Result<Formula, String> {
+        let mut left = self.parse_unary()?;
 
         while let Some(Token::And) = self.current() {
             self.advance();
-            let right = self.parse_unary();
+            let right = self.parse_unary()?;
             left = Formula::and(left, right);
         }
-        left
+        Ok(left)
     }
 
-    fn parse_unary(&mut self) -> Formula {
+    fn parse_unary(&mut self) -> Result<Formula, String> {
         match self.current() {
             Some(Token::Not) => {
                 self.advance();
-                let operand = self.parse_unary();
-                Formula::not(operand)
+                let operand = self.parse_unary()?;
+                Ok(Formula::not(operand))
             },
             Some(Token::Var(name)) => {
                 let f = Formula::var(name);
                 self.advance();
-                f
+                Ok(f)
             },
             Some(Token::LParen) => {
                 self.advance();
-                let expr = self.parse_implies();
-                self.expect(Token::RParen);
-                expr
+                let expr = self.parse_implies()?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
             },
-            _ => panic!("Sintaxă invalidă la poziția {}: Așteptam Variabilă, ! sau (", self.pos),
+            _ => Err(format!("Sintaxă invalidă la poziția {}: Așteptam Variabilă, ! sau (", self.pos)),
         }
     }
 }
 
-pub fn parse_formula(input: &str) -> Formula {
-    let tokens = tokenize(input);
+// Ca `parse_formula`, dar întoarce eroarea de sintaxă în loc să se oprească
+pub fn try_parse_formula(input: &str) -> Result<Formula, String> {
+    let tokens = tokenize(input)?;
     let mut parser = Parser::new(tokens);
     parser.parse()
 }
+
+pub fn parse_formula(input: &str) -> Formula {
+    try_parse_formula(input).unwrap_or_else(|e| panic!("{}", e))
+}
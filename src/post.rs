@@ -0,0 +1,255 @@
+use std::fmt;
+use colored::*;
+
+use crate::formula::Formula;
+use crate::minimizer::BooleanFunction;
+
+// Forma normală algebrică (polinomul Zhegalkin): XOR de monoame.
+// Un monom e o mască de variabile, cu aceeași ordine de biți ca indicii din tabelul de adevăr
+// (prima variabilă e bitul cel mai semnificativ); monomul 0 e constanta 1.
+pub struct Anf {
+    pub var_names: Vec<String>,
+    pub monomials: Vec<usize>,
+}
+
+impl Anf {
+    // Transformata Möbius a tabelului de adevăr
+    pub fn from_table(var_names: Vec<String>, table: &[bool]) -> Self {
+        let mut coeffs = table.to_vec();
+        let n = var_names.len();
+        for bit in 0..n {
+            let step = 1 << bit;
+            for i in 0..coeffs.len() {
+                if i & step != 0 {
+                    coeffs[i] ^= coeffs[i ^ step];
+                }
+            }
+        }
+        let mut monomials: Vec<usize> = (0..coeffs.len()).filter(|&m| coeffs[m]).collect();
+        monomials.sort_by_key(|&m| (m.count_ones(), std::cmp::Reverse(m)));
+        Anf { var_names, monomials }
+    }
+
+    pub fn from_function(func: &BooleanFunction) -> Self {
+        Anf::from_table(func.var_names.clone(), &truth_table(func))
+    }
+
+    pub fn from_formula(formula: &Formula) -> Self {
+        Anf::from_function(&BooleanFunction::from_formula(formula))
+    }
+
+    pub fn degree(&self) -> usize {
+        self.monomials.iter().map(|m| m.count_ones() as usize).max().unwrap_or(0)
+    }
+
+    fn monomial_string(&self, m: usize) -> String {
+        let n = self.var_names.len();
+        if m == 0 { return "1".to_string(); }
+        (0..n).filter(|&i| m & (1 << (n - 1 - i)) != 0).map(|i| self.var_names[i].as_str()).collect()
+    }
+}
+
+// Ex: "1 ⊕ a ⊕ bc"; funcția constantă 0 are polinomul gol
+impl fmt::Display for Anf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.monomials.is_empty() { return write!(f, "0"); }
+        let terms: Vec<String> = self.monomials.iter().map(|&m| self.monomial_string(m)).collect();
+        write!(f, "{}", terms.join(" ⊕ "))
+    }
+}
+
+// Tabelul de adevăr al funcției (don't care-urile sunt considerate 0)
+pub fn truth_table(func: &BooleanFunction) -> Vec<bool> {
+    let mut table = vec![false; 1 << func.var_names.len()];
+    for m in func.minterm_indices() {
+        table[m] = true;
+    }
+    table
+}
+
+// Apartenența la cele cinci clase Post închise maximale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostClasses {
+    pub t0: bool,
+    pub t1: bool,
+    pub self_dual: bool,
+    pub monotone: bool,
+    pub linear: bool,
+}
+
+pub const CLASS_NAMES: [&str; 5] = ["T0", "T1", "S", "M", "L"];
+
+impl PostClasses {
+    pub fn of_table(table: &[bool]) -> Self {
+        let size = table.len();
+        let all = size - 1;
+        let n = size.trailing_zeros() as usize;
+        PostClasses {
+            t0: !table[0],
+            t1: table[all],
+            self_dual: (0..size).all(|i| table[i] != table[all ^ i]),
+            // Creșterea oricărui bit de la 0 la 1 nu scade valoarea
+            monotone: (0..size).all(|i| (0..n).all(|b| i & (1 << b) != 0 || table[i] <= table[i | (1 << b)])),
+            linear: Anf::from_table(vec![String::new(); n], table).degree() <= 1,
+        }
+    }
+
+    pub fn as_array(&self) -> [bool; 5] {
+        [self.t0, self.t1, self.self_dual, self.monotone, self.linear]
+    }
+}
+
+// Un conector logic dat prin tabelul lui de adevăr
+pub struct Connective {
+    pub name: String,
+    pub arity: usize,
+    pub table: Vec<bool>,
+}
+
+impl Connective {
+    pub fn new(name: &str, arity: usize, f: impl Fn(usize) -> bool) -> Self {
+        Connective { name: name.to_string(), arity, table: (0..1 << arity).map(f).collect() }
+    }
+
+    // Conectorii uzuali, după nume sau simbol
+    pub fn parse(text: &str) -> Result<Connective, String> {
+        let bit = |i: usize, k: usize| (i >> k) & 1 == 1;
+        let c = match text.trim().to_lowercase().as_str() {
+            "!" | "~" | "¬" | "not" => Connective::new("¬", 1, |i| i == 0),
+            "&" | "^" | "∧" | "and" => Connective::new("∧", 2, |i| i == 3),
+            "|" | "v" | "∨" | "or" => Connective::new("∨", 2, |i| i != 0),
+            "->" | "→" | "implies" => Connective::new("→", 2, |i| !bit(i, 1) || bit(i, 0)),
+            "<->" | "↔" | "iff" | "eq" => Connective::new("↔", 2, |i| bit(i, 1) == bit(i, 0)),
+            "xor" | "⊕" | "+" => Connective::new("⊕", 2, |i| bit(i, 1) != bit(i, 0)),
+            "nand" | "↑" => Connective::new("↑", 2, |i| i != 3),
+            "nor" | "↓" => Connective::new("↓", 2, |i| i == 0),
+            "maj" => Connective::new("maj", 3, |i| i.count_ones() >= 2),
+            "0" | "false" | "⊥" => Connective::new("0", 0, |_| false),
+            "1" | "true" | "⊤" => Connective::new("1", 0, |_| true),
+            other => return Err(format!("Conector necunoscut: '{}' (ex: and, or, not, ->, xor, nand, nor, <->, maj, 0, 1)", other)),
+        };
+        Ok(c)
+    }
+
+    pub fn classes(&self) -> PostClasses {
+        PostClasses::of_table(&self.table)
+    }
+}
+
+// Mulțimea de conectori separați prin virgulă sau spații (ex: "{->, 0}")
+pub fn parse_connectives(text: &str) -> Result<Vec<Connective>, String> {
+    let list: Vec<Connective> = text.trim().trim_start_matches('{').trim_end_matches('}')
+        .split([',', ' '])
+        .filter(|s| !s.trim().is_empty())
+        .map(Connective::parse)
+        .collect::<Result<_, _>>()?;
+    if list.is_empty() {
+        return Err("Mulțimea de conectori este goală.".to_string());
+    }
+    Ok(list)
+}
+
+fn mark(inside: bool) -> ColoredString {
+    if inside { "✓".green().bold() } else { "✗".red().bold() }
+}
+
+pub fn print_classes(classes: &PostClasses) {
+    for (name, inside) in CLASS_NAMES.iter().zip(classes.as_array()) {
+        print!("  {} {}", name, mark(inside));
+    }
+    println!();
+}
+
+// Teorema lui Post: mulțimea e completă dacă pentru fiecare clasă există un conector care nu îi aparține
+pub fn check_completeness(connectives: &[Connective]) -> bool {
+    println!("\n=== COMPLETITUDINE FUNCȚIONALĂ (TEOREMA LUI POST) ===");
+    println!("{:<8}{}", "", CLASS_NAMES.iter().map(|c| format!("{:<4}", c)).collect::<String>());
+    let all: Vec<[bool; 5]> = connectives.iter().map(|c| c.classes().as_array()).collect();
+    for (c, classes) in connectives.iter().zip(&all) {
+        print!("{:<8}", c.name);
+        for &inside in classes {
+            print!("{}   ", mark(inside));
+        }
+        println!();
+    }
+
+    let closed: Vec<&str> = (0..5)
+        .filter(|&k| all.iter().all(|classes| classes[k]))
+        .map(|k| CLASS_NAMES[k])
+        .collect();
+    if closed.is_empty() {
+        println!("{}", "Mulțimea este funcțional completă.".green().bold());
+    } else {
+        println!("{} {}", "Mulțimea NU este completă: toți conectorii rămân în".red().bold(), closed.join(", ").yellow());
+    }
+    println!("{}", "(T0 păstrează 0, T1 păstrează 1, S autoduale, M monotone, L liniare)".dimmed());
+    closed.is_empty()
+}
+
+// ANF-ul și clasele Post ale funcției
+pub fn analyze(func: &BooleanFunction) {
+    let table = truth_table(func);
+    let anf = Anf::from_table(func.var_names.clone(), &table);
+    println!("\n=== FORMA NORMALĂ ALGEBRICĂ (ZHEGALKIN) ===");
+    println!("{}", anf.to_string().bold().cyan());
+    println!("{}", format!("Grad: {}, {} monoame", anf.degree(), anf.monomials.len()).dimmed());
+
+    println!("\n=== CLASE POST ===");
+    print_classes(&PostClasses::of_table(&table));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    fn anf(text: &str) -> String {
+        Anf::from_formula(&parse_formula(text)).to_string()
+    }
+
+    #[test]
+    fn zhegalkin_polynomials_of_basic_connectives() {
+        assert_eq!(anf("a | b"), "a ⊕ b ⊕ ab");
+        assert_eq!(anf("a -> b"), "1 ⊕ a ⊕ ab");
+        assert_eq!(anf("!a"), "1 ⊕ a");
+        assert_eq!(anf("a & !a"), "0");
+        assert_eq!(Anf::from_formula(&parse_formula("(a & b) | (a & c) | (b & c)")).degree(), 2);
+    }
+
+    #[test]
+    fn anf_evaluates_back_to_the_table() {
+        let func = BooleanFunction::from_formula(&parse_formula("(a -> b) & (c | !a)"));
+        let table = truth_table(&func);
+        let poly = Anf::from_table(func.var_names.clone(), &table);
+        for (idx, &value) in table.iter().enumerate() {
+            let xor = poly.monomials.iter().filter(|&&m| idx & m == m).count() % 2 == 1;
+            assert_eq!(xor, value, "linia {}", idx);
+        }
+    }
+
+    fn classes(name: &str) -> [bool; 5] {
+        Connective::parse(name).unwrap().classes().as_array()
+    }
+
+    #[test]
+    fn post_classes_of_known_connectives() {
+        // Ordinea: T0, T1, S, M, L
+        assert_eq!(classes("and"), [true, true, false, true, false]);
+        assert_eq!(classes("not"), [false, false, true, false, true]);
+        assert_eq!(classes("xor"), [true, false, false, false, true]);
+        assert_eq!(classes("maj"), [true, true, true, true, false]);
+        assert_eq!(classes("nand"), [false; 5]);
+    }
+
+    #[test]
+    fn completeness_follows_post_theorem() {
+        let complete = |text: &str| check_completeness(&parse_connectives(text).unwrap());
+        assert!(complete("{nand}"));
+        assert!(complete("{->, 0}"));
+        assert!(complete("not, and"));
+        assert!(!complete("{and, or}"));
+        assert!(!complete("{xor, <->}"));
+        assert!(parse_connectives("{}").is_err());
+        assert!(parse_connectives("and, foo").is_err());
+    }
+}
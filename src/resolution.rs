@@ -0,0 +1,253 @@
+use colored::*;
+
+use crate::cnf::Cnf;
+use crate::formula::Formula;
+use crate::normal_form::{self, DISTRIBUTION_LIMIT};
+
+// Peste acest număr de clauze derivate căutarea se oprește fără verdict
+pub const CLAUSE_LIMIT: usize = 20000;
+
+// O clauză din derivare: literalii (ca în DIMACS, sortați) și, pentru rezolvenți,
+// clauzele-părinte și variabila eliminată
+#[derive(Debug, Clone)]
+pub struct ResolutionClause {
+    pub literals: Vec<i32>,
+    pub parents: Option<(usize, usize)>,
+    pub pivot: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    // Indicele clauzei vide
+    Refuted(usize),
+    // Nicio rezolvare nouă posibilă: mulțimea de clauze e satisfiabilă
+    Saturated,
+    LimitReached,
+}
+
+// Derivarea prin rezoluție: clauzele inițiale, urmate de rezolvenți (un DAG prin `parents`)
+pub struct Resolution {
+    pub cnf: Cnf,
+    pub clauses: Vec<ResolutionClause>,
+    pub outcome: Outcome,
+}
+
+fn normalize(mut lits: Vec<i32>) -> Vec<i32> {
+    lits.sort_by_key(|l| (l.abs(), *l));
+    lits.dedup();
+    lits
+}
+
+fn is_subset(a: &[i32], b: &[i32]) -> bool {
+    a.iter().all(|l| b.contains(l))
+}
+
+// Rezolventul pe `pivot` (pozitiv în a, negativ în b); None dacă ar fi tautologie
+fn resolve(a: &[i32], b: &[i32], pivot: i32) -> Option<Vec<i32>> {
+    let lits = normalize(a.iter().filter(|&&l| l != pivot).chain(b.iter().filter(|&&l| l != -pivot)).copied().collect());
+    if lits.windows(2).any(|w| w[0] == -w[1]) { None } else { Some(lits) }
+}
+
+impl Resolution {
+    // Forma clauzală a conjuncției formulelor: prin distributivitate, sau Tseitin dacă aceasta ar exploda
+    pub fn clause_form(formulas: &[Formula]) -> Cnf {
+        let Some(f) = formulas.iter().cloned().reduce(Formula::and) else {
+            return Cnf::new(Vec::new());
+        };
+        let mut cnf = if normal_form::estimated_size(&f, true) <= DISTRIBUTION_LIMIT {
+            Cnf::from_cnf_formula(&normal_form::to_cnf(&f)).expect("Distributivitatea produce FNC")
+        } else {
+            Cnf::tseitin(&f)
+        };
+        cnf.simplify();
+        cnf
+    }
+
+    // Căutarea clauzei vide cu algoritmul clauzei date: mereu cea mai scurtă clauză neprocesată
+    // e rezolvată cu toate cele procesate; rezolvenții subsumați de clauze existente sunt ignorați.
+    pub fn refute(cnf: Cnf) -> Resolution {
+        let mut clauses: Vec<ResolutionClause> = cnf.clauses.iter()
+            .map(|c| ResolutionClause { literals: normalize(c.clone()), parents: None, pivot: None })
+            .collect();
+        let mut outcome = Outcome::Saturated;
+        if let Some(empty) = clauses.iter().position(|c| c.literals.is_empty()) {
+            return Resolution { cnf, clauses, outcome: Outcome::Refuted(empty) };
+        }
+
+        let mut processed: Vec<usize> = Vec::new();
+        let mut pending: Vec<usize> = (0..clauses.len()).collect();
+
+        'search: while !pending.is_empty() {
+            let pick = (0..pending.len()).min_by_key(|&i| (clauses[pending[i]].literals.len(), pending[i])).expect("Listă nevidă");
+            let given = pending.remove(pick);
+
+            for &other in &processed {
+                for lit in clauses[given].literals.clone() {
+                    if !clauses[other].literals.contains(&-lit) { continue; }
+                    let (pos, neg) = if lit > 0 { (given, other) } else { (other, given) };
+                    let Some(resolvent) = resolve(&clauses[pos].literals, &clauses[neg].literals, lit.abs()) else { continue };
+                    if clauses.iter().any(|c| is_subset(&c.literals, &resolvent)) { continue; }
+
+                    let empty = resolvent.is_empty();
+                    clauses.push(ResolutionClause { literals: resolvent, parents: Some((pos, neg)), pivot: Some(lit.abs()) });
+                    if empty {
+                        outcome = Outcome::Refuted(clauses.len() - 1);
+                        break 'search;
+                    }
+                    if clauses.len() >= CLAUSE_LIMIT {
+                        outcome = Outcome::LimitReached;
+                        break 'search;
+                    }
+                    pending.push(clauses.len() - 1);
+                }
+            }
+            processed.push(given);
+        }
+        Resolution { cnf, clauses, outcome }
+    }
+
+    pub fn from_formulas(formulas: &[Formula]) -> Resolution {
+        Resolution::refute(Resolution::clause_form(formulas))
+    }
+
+    // Clauzele folosite în respingere, în ordinea derivării
+    pub fn refutation(&self) -> Vec<usize> {
+        let Outcome::Refuted(empty) = self.outcome else { return Vec::new() };
+        let mut used = vec![false; self.clauses.len()];
+        let mut stack = vec![empty];
+        while let Some(i) = stack.pop() {
+            if used[i] { continue; }
+            used[i] = true;
+            if let Some((a, b)) = self.clauses[i].parents {
+                stack.push(a);
+                stack.push(b);
+            }
+        }
+        (0..self.clauses.len()).filter(|&i| used[i]).collect()
+    }
+
+    fn clause_string(&self, i: usize) -> String {
+        self.cnf.clause_string(&self.clauses[i].literals)
+    }
+
+    // Pașii numerotați: clauza, apoi premisa sau părinții și literalul rezolvat
+    pub fn print(&self) {
+        println!("\n{}", "=== REZOLUȚIE ===".purple().bold());
+        println!("Clauze inițiale: {}", self.cnf.to_set_string());
+        match self.outcome {
+            Outcome::Refuted(_) => {
+                let steps = self.refutation();
+                let number = |i: usize| steps.iter().position(|&s| s == i).expect("Părinte din respingere") + 1;
+                for (n, &i) in steps.iter().enumerate() {
+                    let clause = &self.clauses[i];
+                    let text = self.clause_string(i);
+                    let justification = match (clause.parents, clause.pivot) {
+                        (Some((a, b)), Some(pivot)) => format!("rezolvent din {}, {} pe {}", number(a), number(b), self.cnf.var_names[pivot as usize - 1]),
+                        _ => "premisă".to_string(),
+                    };
+                    let text = if clause.literals.is_empty() { text.red().bold().to_string() } else { text };
+                    println!("{:>4}. {:<30} {}", n + 1, text, justification.dimmed());
+                }
+                println!("{}", format!("Clauza vidă derivată ({} clauze generate în total).", self.clauses.len()).dimmed());
+            },
+            Outcome::Saturated => println!("{}", format!("Saturare fără clauza vidă ({} clauze): mulțimea e satisfiabilă.", self.clauses.len()).dimmed()),
+            Outcome::LimitReached => println!("{}", format!("Limita de {} clauze a fost atinsă fără verdict.", CLAUSE_LIMIT).dimmed()),
+        }
+    }
+
+    // Graful respingerii (sau al tuturor clauzelor, dacă nu există una) în format Graphviz
+    pub fn to_dot(&self) -> String {
+        let nodes = match self.outcome {
+            Outcome::Refuted(_) => self.refutation(),
+            _ => (0..self.clauses.len()).collect(),
+        };
+        let mut dot = String::from("digraph rezolutie {\n  rankdir=TB;\n  node [shape=box, fontname=\"Helvetica\"];\n");
+        for &i in &nodes {
+            let style = if self.clauses[i].literals.is_empty() { ", color=red, fontcolor=red" }
+                else if self.clauses[i].parents.is_none() { ", style=filled, fillcolor=lightgrey" }
+                else { "" };
+            dot.push_str(&format!("  c{} [label=\"{}\"{}];\n", i, self.clause_string(i).replace('"', "\\\""), style));
+        }
+        for &i in &nodes {
+            if let (Some((a, b)), Some(pivot)) = (self.clauses[i].parents, self.clauses[i].pivot) {
+                let name = &self.cnf.var_names[pivot as usize - 1];
+                dot.push_str(&format!("  c{} -> c{} [label=\"{}\"];\n", a, i, name));
+                dot.push_str(&format!("  c{} -> c{} [label=\"¬{}\"];\n", b, i, name));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    fn formulas(texts: &[&str]) -> Vec<Formula> {
+        texts.iter().map(|t| parse_formula(t)).collect()
+    }
+
+    // Fiecare rezolvent al respingerii provine din părinții lui, pe pivotul declarat
+    fn assert_valid_refutation(res: &Resolution) {
+        let steps = res.refutation();
+        assert!(res.clauses[*steps.last().unwrap()].literals.is_empty());
+        for &i in &steps {
+            let clause = &res.clauses[i];
+            match (clause.parents, clause.pivot) {
+                (Some((a, b)), Some(pivot)) => {
+                    assert!(a < i && b < i && steps.contains(&a) && steps.contains(&b));
+                    assert!(res.clauses[a].literals.contains(&pivot));
+                    assert!(res.clauses[b].literals.contains(&-pivot));
+                    let expected = resolve(&res.clauses[a].literals, &res.clauses[b].literals, pivot);
+                    assert_eq!(expected.as_ref(), Some(&clause.literals));
+                },
+                _ => assert!(res.cnf.clauses.iter().any(|c| normalize(c.clone()) == clause.literals)),
+            }
+        }
+    }
+
+    #[test]
+    fn modus_ponens_is_refuted() {
+        let res = Resolution::from_formulas(&formulas(&["p -> q", "p", "!q"]));
+        assert!(matches!(res.outcome, Outcome::Refuted(_)));
+        assert_eq!(res.refutation().len(), 5);
+        assert_valid_refutation(&res);
+        let dot = res.to_dot();
+        assert!(dot.contains("color=red") && dot.contains("[label=\"¬q\"]"));
+    }
+
+    #[test]
+    fn pigeonhole_three_in_two() {
+        let texts = ["a1 | a2", "b1 | b2", "c1 | c2", "!a1 | !b1", "!a1 | !c1", "!b1 | !c1", "!a2 | !b2", "!a2 | !c2", "!b2 | !c2"];
+        let res = Resolution::from_formulas(&formulas(&texts));
+        assert!(matches!(res.outcome, Outcome::Refuted(_)));
+        assert_valid_refutation(&res);
+    }
+
+    #[test]
+    fn satisfiable_sets_saturate() {
+        let res = Resolution::from_formulas(&formulas(&["p | q", "!p | r", "!q | r"]));
+        assert_eq!(res.outcome, Outcome::Saturated);
+        assert!(res.refutation().is_empty());
+        assert!(res.clauses.iter().all(|c| !c.literals.is_empty()));
+    }
+
+    #[test]
+    fn tautologies_and_subsumed_resolvents_are_skipped() {
+        assert_eq!(resolve(&[1, 2], &[-1, -2], 1), None);
+        assert_eq!(resolve(&[1, 2], &[-1, 3], 1), Some(vec![2, 3]));
+        // {p, q}, {¬p, q}: rezolventul {q} e adăugat o singură dată
+        let res = Resolution::from_formulas(&formulas(&["(p | q) & (!p | q)"]));
+        assert_eq!(res.outcome, Outcome::Saturated);
+        assert_eq!(res.clauses.iter().filter(|c| c.literals == vec![2]).count(), 1);
+    }
+
+    #[test]
+    fn empty_premise_is_an_immediate_refutation() {
+        let mut cnf = Cnf::new(vec!["p".into()]);
+        cnf.clauses = vec![vec![1], vec![]];
+        assert_eq!(Resolution::refute(cnf).outcome, Outcome::Refuted(1));
+    }
+}
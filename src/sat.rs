@@ -0,0 +1,545 @@
+use crate::cnf::Cnf;
+use crate::formula::{Formula, Valuation};
+
+// Solver SAT cu învățare de clauze (CDCL): propagare cu doi literali urmăriți,
+// analiza conflictelor până la primul punct de dominare (1UIP), salt înapoi nesecvențial,
+// euristica VSIDS cu salvarea polarității și restarturi după secvența Luby.
+
+// Un literal intern: 2 * variabilă (de la 0) + 1 dacă e negat
+type Lit = usize;
+
+// Numărul de conflicte dintre două restarturi, înmulțit cu termenul din secvența Luby
+const RESTART_BASE: u64 = 100;
+const ACTIVITY_DECAY: f64 = 0.95;
+
+fn lit_of(dimacs: i32) -> Lit {
+    2 * (dimacs.unsigned_abs() as usize - 1) + (dimacs < 0) as usize
+}
+
+fn var_of(l: Lit) -> usize {
+    l / 2
+}
+
+fn is_negated(l: Lit) -> bool {
+    l & 1 == 1
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Stats {
+    pub decisions: u64,
+    pub propagations: u64,
+    pub conflicts: u64,
+    pub learned: u64,
+    pub restarts: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SatResult {
+    // Valoarea fiecărei variabile, în ordinea din `Cnf::var_names`
+    Sat(Vec<bool>),
+    Unsat,
+}
+
+// Variabilele neatribuite, într-un heap binar ordonat după activitate (la egalitate, indicele mai mic).
+// `positions[v]` e locul lui v în heap, ca activitatea să poată crește fără o căutare liniară.
+struct OrderHeap {
+    heap: Vec<usize>,
+    positions: Vec<Option<usize>>,
+}
+
+impl OrderHeap {
+    fn new(num_vars: usize) -> Self {
+        OrderHeap { heap: (0..num_vars).collect(), positions: (0..num_vars).map(Some).collect() }
+    }
+
+    fn before(activity: &[f64], a: usize, b: usize) -> bool {
+        activity[a] > activity[b] || (activity[a] == activity[b] && a < b)
+    }
+
+    fn place(&mut self, i: usize, v: usize) {
+        self.heap[i] = v;
+        self.positions[v] = Some(i);
+    }
+
+    fn sift_up(&mut self, mut i: usize, activity: &[f64]) {
+        let v = self.heap[i];
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !OrderHeap::before(activity, v, self.heap[parent]) { break; }
+            self.place(i, self.heap[parent]);
+            i = parent;
+        }
+        self.place(i, v);
+    }
+
+    fn sift_down(&mut self, mut i: usize, activity: &[f64]) {
+        let v = self.heap[i];
+        loop {
+            let left = 2 * i + 1;
+            if left >= self.heap.len() { break; }
+            let right = left + 1;
+            let child = if right < self.heap.len() && OrderHeap::before(activity, self.heap[right], self.heap[left]) { right } else { left };
+            if !OrderHeap::before(activity, self.heap[child], v) { break; }
+            self.place(i, self.heap[child]);
+            i = child;
+        }
+        self.place(i, v);
+    }
+
+    fn insert(&mut self, v: usize, activity: &[f64]) {
+        if self.positions[v].is_some() { return; }
+        self.heap.push(v);
+        self.sift_up(self.heap.len() - 1, activity);
+    }
+
+    // Activitatea lui v a crescut: urcă în heap
+    fn increased(&mut self, v: usize, activity: &[f64]) {
+        if let Some(i) = self.positions[v] {
+            self.sift_up(i, activity);
+        }
+    }
+
+    fn pop(&mut self, activity: &[f64]) -> Option<usize> {
+        let top = *self.heap.first()?;
+        let last = self.heap.pop().expect("Heap nevid");
+        self.positions[top] = None;
+        if !self.heap.is_empty() {
+            self.place(0, last);
+            self.sift_down(0, activity);
+        }
+        Some(top)
+    }
+}
+
+struct Solver {
+    clauses: Vec<Vec<Lit>>,
+    // Pentru fiecare literal: clauzele în care e urmărit (pe poziția 0 sau 1)
+    watches: Vec<Vec<usize>>,
+    // -1 nedefinit, 0 fals, 1 adevărat
+    assigns: Vec<i8>,
+    levels: Vec<usize>,
+    reasons: Vec<Option<usize>>,
+    trail: Vec<Lit>,
+    // Începutul fiecărui nivel de decizie pe `trail`
+    trail_lim: Vec<usize>,
+    queue_head: usize,
+    activity: Vec<f64>,
+    activity_inc: f64,
+    order: OrderHeap,
+    phase: Vec<bool>,
+    seen: Vec<bool>,
+    stats: Stats,
+}
+
+impl Solver {
+    fn new(num_vars: usize) -> Self {
+        Solver {
+            clauses: Vec::new(),
+            watches: vec![Vec::new(); 2 * num_vars],
+            assigns: vec![-1; num_vars],
+            levels: vec![0; num_vars],
+            reasons: vec![None; num_vars],
+            trail: Vec::new(),
+            trail_lim: Vec::new(),
+            queue_head: 0,
+            activity: vec![0.0; num_vars],
+            activity_inc: 1.0,
+            order: OrderHeap::new(num_vars),
+            phase: vec![false; num_vars],
+            seen: vec![false; num_vars],
+            stats: Stats::default(),
+        }
+    }
+
+    fn level(&self) -> usize {
+        self.trail_lim.len()
+    }
+
+    // Some(true/false) pentru un literal atribuit, None altfel
+    fn value(&self, l: Lit) -> Option<bool> {
+        match self.assigns[var_of(l)] {
+            -1 => None,
+            a => Some((a == 1) != is_negated(l)),
+        }
+    }
+
+    fn enqueue(&mut self, l: Lit, reason: Option<usize>) {
+        let v = var_of(l);
+        self.assigns[v] = !is_negated(l) as i8;
+        self.levels[v] = self.level();
+        self.reasons[v] = reason;
+        self.trail.push(l);
+    }
+
+    // Adaugă o clauză inițială; false dacă formula devine evident nesatisfiabilă
+    fn add_clause(&mut self, clause: &[i32]) -> bool {
+        let mut lits: Vec<Lit> = clause.iter().map(|&l| lit_of(l)).collect();
+        lits.sort();
+        lits.dedup();
+        if lits.windows(2).any(|w| w[0] ^ 1 == w[1]) {
+            return true;
+        }
+        match lits.len() {
+            0 => false,
+            1 => match self.value(lits[0]) {
+                Some(v) => v,
+                None => {
+                    self.enqueue(lits[0], None);
+                    true
+                },
+            },
+            _ => {
+                self.attach(lits);
+                true
+            },
+        }
+    }
+
+    fn attach(&mut self, lits: Vec<Lit>) -> usize {
+        let index = self.clauses.len();
+        self.watches[lits[0]].push(index);
+        self.watches[lits[1]].push(index);
+        self.clauses.push(lits);
+        index
+    }
+
+    // Propagarea unitară; întoarce clauza în conflict, dacă apare una
+    fn propagate(&mut self) -> Option<usize> {
+        while self.queue_head < self.trail.len() {
+            let false_lit = self.trail[self.queue_head] ^ 1;
+            self.queue_head += 1;
+            self.stats.propagations += 1;
+
+            let watching = std::mem::take(&mut self.watches[false_lit]);
+            let mut kept = Vec::with_capacity(watching.len());
+            let mut conflict = None;
+            let mut i = 0;
+            while i < watching.len() {
+                let c = watching[i];
+                i += 1;
+                // Literalul fals e mutat pe poziția 1
+                if self.clauses[c][0] == false_lit {
+                    self.clauses[c].swap(0, 1);
+                }
+                let first = self.clauses[c][0];
+                if self.value(first) == Some(true) {
+                    kept.push(c);
+                    continue;
+                }
+                // Un alt literal care nu e fals preia urmărirea
+                if let Some(k) = (2..self.clauses[c].len()).find(|&k| self.value(self.clauses[c][k]) != Some(false)) {
+                    self.clauses[c].swap(1, k);
+                    let new_watch = self.clauses[c][1];
+                    self.watches[new_watch].push(c);
+                    continue;
+                }
+                kept.push(c);
+                if self.value(first) == Some(false) {
+                    conflict = Some(c);
+                    kept.extend_from_slice(&watching[i..]);
+                    break;
+                }
+                self.enqueue(first, Some(c));
+            }
+            self.watches[false_lit] = kept;
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+        None
+    }
+
+    fn bump(&mut self, v: usize) {
+        self.activity[v] += self.activity_inc;
+        if self.activity[v] > 1e100 {
+            for a in &mut self.activity {
+                *a *= 1e-100;
+            }
+            self.activity_inc *= 1e-100;
+        }
+        // Reducerea la scară păstrează ordinea, deci ajunge să urce doar v
+        self.order.increased(v, &self.activity);
+    }
+
+    // Clauza învățată (literalul care devine unitar pe poziția 0) și nivelul de întoarcere
+    fn analyze(&mut self, conflict: usize) -> (Vec<Lit>, usize) {
+        let mut learnt: Vec<Lit> = vec![0];
+        let mut pending = 0;
+        let mut index = self.trail.len();
+        let mut clause = conflict;
+        let mut resolved: Option<Lit> = None;
+
+        loop {
+            let lits = self.clauses[clause].clone();
+            // Primul literal al clauzei-motiv este chiar cel rezolvat
+            let skip = if resolved.is_some() { 1 } else { 0 };
+            for &q in &lits[skip..] {
+                let v = var_of(q);
+                if !self.seen[v] && self.levels[v] > 0 {
+                    self.seen[v] = true;
+                    self.bump(v);
+                    if self.levels[v] == self.level() {
+                        pending += 1;
+                    } else {
+                        learnt.push(q);
+                    }
+                }
+            }
+
+            // Următorul literal marcat de pe nivelul curent, de la coada trail-ului
+            loop {
+                index -= 1;
+                if self.seen[var_of(self.trail[index])] { break; }
+            }
+            let p = self.trail[index];
+            self.seen[var_of(p)] = false;
+            pending -= 1;
+            if pending == 0 {
+                learnt[0] = p ^ 1;
+                break;
+            }
+            resolved = Some(p);
+            clause = self.reasons[var_of(p)].expect("Literal propagat fără motiv");
+        }
+
+        for &l in &learnt[1..] {
+            self.seen[var_of(l)] = false;
+        }
+
+        // Literalul cu nivelul cel mai mare (după cel unitar) devine al doilea urmărit
+        let mut back_level = 0;
+        if learnt.len() > 1 {
+            let max = (1..learnt.len()).max_by_key(|&i| self.levels[var_of(learnt[i])]).unwrap_or(1);
+            learnt.swap(1, max);
+            back_level = self.levels[var_of(learnt[1])];
+        }
+        (learnt, back_level)
+    }
+
+    fn backtrack(&mut self, level: usize) {
+        if self.level() <= level { return; }
+        let start = self.trail_lim[level];
+        for &l in &self.trail[start..] {
+            let v = var_of(l);
+            self.phase[v] = !is_negated(l);
+            self.assigns[v] = -1;
+            self.reasons[v] = None;
+            self.order.insert(v, &self.activity);
+        }
+        self.trail.truncate(start);
+        self.trail_lim.truncate(level);
+        self.queue_head = start;
+    }
+
+    // Variabila neatribuită cu activitatea cea mai mare; cele atribuite între timp sunt scoase din heap
+    fn pick_branch(&mut self) -> Option<Lit> {
+        while let Some(v) = self.order.pop(&self.activity) {
+            if self.assigns[v] == -1 {
+                return Some(2 * v + (!self.phase[v]) as usize);
+            }
+        }
+        None
+    }
+
+    fn solve(&mut self) -> SatResult {
+        let mut restart = 1;
+        let mut budget = luby(restart) * RESTART_BASE;
+        let mut conflicts_since = 0;
+
+        loop {
+            if let Some(conflict) = self.propagate() {
+                self.stats.conflicts += 1;
+                conflicts_since += 1;
+                if self.level() == 0 {
+                    return SatResult::Unsat;
+                }
+                let (learnt, back_level) = self.analyze(conflict);
+                self.backtrack(back_level);
+                self.stats.learned += 1;
+                if learnt.len() == 1 {
+                    self.enqueue(learnt[0], None);
+                } else {
+                    let asserting = learnt[0];
+                    let c = self.attach(learnt);
+                    self.enqueue(asserting, Some(c));
+                }
+                self.activity_inc /= ACTIVITY_DECAY;
+                continue;
+            }
+
+            if conflicts_since >= budget {
+                self.stats.restarts += 1;
+                restart += 1;
+                budget = luby(restart) * RESTART_BASE;
+                conflicts_since = 0;
+                self.backtrack(0);
+                continue;
+            }
+
+            match self.pick_branch() {
+                Some(l) => {
+                    self.stats.decisions += 1;
+                    self.trail_lim.push(self.trail.len());
+                    self.enqueue(l, None);
+                },
+                None => return SatResult::Sat(self.assigns.iter().map(|&a| a == 1).collect()),
+            }
+        }
+    }
+}
+
+// Secvența Luby: 1 1 2 1 1 2 4 1 1 2 1 1 2 4 8 ... (termenul i, de la 1)
+fn luby(i: u64) -> u64 {
+    let mut k = 1;
+    while (1 << k) - 1 < i {
+        k += 1;
+    }
+    if (1 << k) - 1 == i {
+        1 << (k - 1)
+    } else {
+        luby(i - (1 << (k - 1)) + 1)
+    }
+}
+
+pub fn solve(cnf: &Cnf) -> (SatResult, Stats) {
+    let mut solver = Solver::new(cnf.num_vars());
+    for clause in &cnf.clauses {
+        if !solver.add_clause(clause) {
+            return (SatResult::Unsat, solver.stats);
+        }
+    }
+    let result = solver.solve();
+    (result, solver.stats)
+}
+
+// Un model al conjuncției formulelor (prin codificarea Tseitin), restrâns la variabilele lor
+pub fn satisfy(formulas: &[Formula]) -> (Option<Valuation>, Stats) {
+    let Some(conjunction) = formulas.iter().cloned().reduce(Formula::and) else {
+        return (Some(Valuation::new()), Stats::default());
+    };
+    let cnf = Cnf::tseitin(&conjunction);
+    let (result, stats) = solve(&cnf);
+    let model = match result {
+        SatResult::Sat(values) => {
+            let original = conjunction.variables();
+            Some(cnf.var_names.iter().zip(values)
+                .filter(|(name, _)| original.contains(name))
+                .map(|(name, value)| (name.clone(), value))
+                .collect())
+        },
+        SatResult::Unsat => None,
+    };
+    (model, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    // Principiul cutiei: `pigeons` porumbei în `holes` cuști, cel mult unul pe cușcă
+    fn pigeonhole(pigeons: usize, holes: usize) -> Cnf {
+        let var = |p: usize, h: usize| (p * holes + h + 1) as i32;
+        let mut cnf = Cnf::new((0..pigeons * holes).map(|i| format!("p{}_{}", i / holes, i % holes)).collect());
+        for p in 0..pigeons {
+            cnf.clauses.push((0..holes).map(|h| var(p, h)).collect());
+        }
+        for h in 0..holes {
+            for p in 0..pigeons {
+                for q in p + 1..pigeons {
+                    cnf.clauses.push(vec![-var(p, h), -var(q, h)]);
+                }
+            }
+        }
+        cnf
+    }
+
+    fn satisfies(cnf: &Cnf, values: &[bool]) -> bool {
+        cnf.clauses.iter().all(|c| c.iter().any(|&l| values[l.unsigned_abs() as usize - 1] == (l > 0)))
+    }
+
+    #[test]
+    fn pigeonhole_three() {
+        let (result, stats) = solve(&pigeonhole(4, 3));
+        assert_eq!(result, SatResult::Unsat);
+        assert!(stats.conflicts > 0 && stats.learned > 0);
+
+        let cnf = pigeonhole(3, 3);
+        match solve(&cnf).0 {
+            SatResult::Sat(values) => assert!(satisfies(&cnf, &values)),
+            SatResult::Unsat => panic!("3 porumbei încap în 3 cuști"),
+        }
+    }
+
+    #[test]
+    fn agrees_with_brute_force_on_random_3sat() {
+        let mut state = 0x9e37_79b9_7f4a_7c15;
+        for _ in 0..300 {
+            let n = 3 + (next(&mut state) % 8) as usize;
+            let m = 1 + (next(&mut state) % (5 * n as u64)) as usize;
+            let mut cnf = Cnf::new((1..=n).map(|i| format!("x{}", i)).collect());
+            for _ in 0..m {
+                cnf.clauses.push((0..3).map(|_| {
+                    let v = 1 + (next(&mut state) % n as u64) as i32;
+                    if next(&mut state).is_multiple_of(2) { v } else { -v }
+                }).collect());
+            }
+            let brute = (0..1usize << n).any(|idx| {
+                let values: Vec<bool> = (0..n).map(|i| (idx >> i) & 1 == 1).collect();
+                satisfies(&cnf, &values)
+            });
+            match solve(&cnf).0 {
+                SatResult::Sat(values) => assert!(satisfies(&cnf, &values), "model greșit pentru {:?}", cnf.clauses),
+                SatResult::Unsat => assert!(!brute, "UNSAT greșit pentru {:?}", cnf.clauses),
+            }
+        }
+    }
+
+    #[test]
+    fn trivial_instances() {
+        let mut cnf = Cnf::new(vec!["a".into()]);
+        assert!(matches!(solve(&cnf).0, SatResult::Sat(_)));
+        cnf.clauses = vec![vec![1], vec![-1]];
+        assert_eq!(solve(&cnf).0, SatResult::Unsat);
+        cnf.clauses = vec![vec![]];
+        assert_eq!(solve(&cnf).0, SatResult::Unsat);
+        cnf.clauses = vec![vec![1, -1]];
+        assert!(matches!(solve(&cnf).0, SatResult::Sat(_)));
+    }
+
+    #[test]
+    fn formulas_through_tseitin() {
+        let model = satisfy(&[parse_formula("p -> q"), parse_formula("p")]).0.unwrap();
+        assert_eq!(model.len(), 2);
+        assert!(model["p"] && model["q"]);
+        assert!(satisfy(&[parse_formula("(p -> q) & p & !q")]).0.is_none());
+    }
+
+    #[test]
+    fn luby_sequence() {
+        let terms: Vec<u64> = (1..=15).map(luby).collect();
+        assert_eq!(terms, vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn order_heap_pops_by_activity() {
+        let mut activity = vec![0.0, 3.0, 1.0, 3.0, 2.0];
+        let mut heap = OrderHeap::new(5);
+        for v in 0..5 {
+            heap.increased(v, &activity);
+        }
+        assert_eq!(heap.pop(&activity), Some(1));
+        activity[0] = 5.0;
+        heap.increased(0, &activity);
+        heap.insert(1, &activity);
+        let order: Vec<usize> = std::iter::from_fn(|| heap.pop(&activity)).collect();
+        assert_eq!(order, vec![0, 1, 3, 4, 2]);
+    }
+}
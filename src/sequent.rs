@@ -0,0 +1,403 @@
+use std::fmt;
+
+use crate::formula::Formula;
+use crate::parser::parse_formula;
+use crate::tableau::node::{NodeStatus, TableauNode};
+use crate::truth_table::latex_formula;
+
+// Secvent Γ ⊢ Δ: conjuncția din stânga implică disjuncția din dreapta
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequent {
+    pub left: Vec<Formula>,
+    pub right: Vec<Formula>,
+}
+
+// Regulile calculului LK fără tăietură; axioma e generalizată (Γ, A ⊢ A, Δ), deci slăbirea nu e necesară
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Axiom,
+    NotL,
+    NotR,
+    AndL,
+    AndR,
+    OrL,
+    OrR,
+    ImpliesL,
+    ImpliesR,
+}
+
+impl Rule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::Axiom => "Ax",
+            Rule::NotL => "¬L",
+            Rule::NotR => "¬R",
+            Rule::AndL => "∧L",
+            Rule::AndR => "∧R",
+            Rule::OrL => "∨L",
+            Rule::OrR => "∨R",
+            Rule::ImpliesL => "→L",
+            Rule::ImpliesR => "→R",
+        }
+    }
+
+    fn latex(&self) -> &'static str {
+        match self {
+            Rule::Axiom => "\\mathrm{Ax}",
+            Rule::NotL => "\\neg L",
+            Rule::NotR => "\\neg R",
+            Rule::AndL => "\\land L",
+            Rule::AndR => "\\land R",
+            Rule::OrL => "\\lor L",
+            Rule::OrR => "\\lor R",
+            Rule::ImpliesL => "\\to L",
+            Rule::ImpliesR => "\\to R",
+        }
+    }
+}
+
+// O derivare: secventul concluzie, regula aplicată și derivările premiselor
+#[derive(Debug, Clone)]
+pub struct Proof {
+    pub sequent: Sequent,
+    pub rule: Rule,
+    pub premises: Vec<Proof>,
+}
+
+fn join(formulas: &[Formula]) -> String {
+    formulas.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+impl fmt::Display for Sequent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.left.is_empty(), self.right.is_empty()) {
+            (true, true) => write!(f, "⊢"),
+            (true, false) => write!(f, "⊢ {}", join(&self.right)),
+            (false, true) => write!(f, "{} ⊢", join(&self.left)),
+            (false, false) => write!(f, "{} ⊢ {}", join(&self.left), join(&self.right)),
+        }
+    }
+}
+
+fn without(formulas: &[Formula], i: usize) -> Vec<Formula> {
+    let mut rest = formulas.to_vec();
+    rest.remove(i);
+    rest
+}
+
+impl Sequent {
+    pub fn new(left: Vec<Formula>, right: Vec<Formula>) -> Self {
+        Sequent { left, right }
+    }
+
+    pub fn is_axiom(&self) -> bool {
+        self.left.iter().any(|f| self.right.contains(f))
+    }
+
+    // Premisele regulii aplicate pe formula `i` din stânga (`on_left`) sau din dreapta
+    fn premises(&self, on_left: bool, i: usize) -> Option<(Rule, Vec<Sequent>)> {
+        let with = |mut side: Vec<Formula>, extra: &[&Formula]| {
+            side.extend(extra.iter().map(|f| (*f).clone()));
+            side
+        };
+        if on_left {
+            let (l, r) = (without(&self.left, i), &self.right);
+            Some(match &self.left[i] {
+                Formula::Var(_) => return None,
+                Formula::Not(a) => (Rule::NotL, vec![Sequent::new(l, with(r.clone(), &[a]))]),
+                Formula::And(a, b) => (Rule::AndL, vec![Sequent::new(with(l, &[a, b]), r.clone())]),
+                Formula::Or(a, b) => (Rule::OrL, vec![Sequent::new(with(l.clone(), &[a]), r.clone()), Sequent::new(with(l, &[b]), r.clone())]),
+                Formula::Implies(a, b) => (Rule::ImpliesL, vec![Sequent::new(l.clone(), with(r.clone(), &[a])), Sequent::new(with(l, &[b]), r.clone())]),
+            })
+        } else {
+            let (l, r) = (&self.left, without(&self.right, i));
+            Some(match &self.right[i] {
+                Formula::Var(_) => return None,
+                Formula::Not(a) => (Rule::NotR, vec![Sequent::new(with(l.clone(), &[a]), r)]),
+                Formula::And(a, b) => (Rule::AndR, vec![Sequent::new(l.clone(), with(r.clone(), &[a])), Sequent::new(l.clone(), with(r, &[b]))]),
+                Formula::Or(a, b) => (Rule::OrR, vec![Sequent::new(l.clone(), with(r, &[a, b]))]),
+                Formula::Implies(a, b) => (Rule::ImpliesR, vec![Sequent::new(with(l.clone(), &[a]), with(r, &[b]))]),
+            })
+        }
+    }
+
+    // Căutarea unei demonstrații. Toate regulile LK sunt inversabile, deci ordinea nu afectează
+    // rezultatul; regulile cu o singură premisă sunt aplicate primele, pentru arbori mai mici.
+    // Dacă secventul nu e demonstrabil, întoarce o frunză atomică ce nu e axiomă (un contraexemplu:
+    // stânga adevărată, dreapta falsă).
+    pub fn prove(&self) -> Result<Proof, Sequent> {
+        if self.is_axiom() {
+            return Ok(Proof { sequent: self.clone(), rule: Rule::Axiom, premises: Vec::new() });
+        }
+
+        let candidates = (0..self.left.len()).map(|i| (true, i)).chain((0..self.right.len()).map(|i| (false, i)));
+        let mut best: Option<(Rule, Vec<Sequent>)> = None;
+        for (on_left, i) in candidates {
+            if let Some((rule, premises)) = self.premises(on_left, i) {
+                let single = premises.len() == 1;
+                if best.is_none() || single {
+                    best = Some((rule, premises));
+                }
+                if single { break; }
+            }
+        }
+
+        let Some((rule, premises)) = best else { return Err(self.clone()) };
+        let premises = premises.iter().map(|s| s.prove()).collect::<Result<Vec<_>, _>>()?;
+        Ok(Proof { sequent: self.clone(), rule, premises })
+    }
+
+    // "A, B ⊢ C" sau "A, B |- C"; fără simbol, textul e doar partea dreaptă
+    pub fn parse(text: &str) -> Sequent {
+        let (left, right) = match text.split_once("⊢").or_else(|| text.split_once("|-")) {
+            Some((l, r)) => (l, r),
+            None => ("", text),
+        };
+        let side = |s: &str| s.split(',').map(str::trim).filter(|p| !p.is_empty()).map(parse_formula).collect();
+        Sequent::new(side(left), side(right))
+    }
+
+    fn latex(&self) -> String {
+        let side = |fs: &[Formula]| fs.iter().map(|f| latex_formula(f, true)).collect::<Vec<_>>().join(", ");
+        format!("{} \\vdash {}", side(&self.left), side(&self.right))
+    }
+}
+
+// Secventul unui nod de tablou: formulele pozitive în stânga, cele negate (fără negație) în dreapta.
+// Un tablou închis pentru Γ, ¬Δ corespunde unei demonstrații LK a lui Γ ⊢ Δ.
+fn node_sequent(formulas: &[Formula]) -> Sequent {
+    let mut sequent = Sequent::new(Vec::new(), Vec::new());
+    for f in formulas {
+        match f {
+            Formula::Not(inner) => sequent.right.push((**inner).clone()),
+            _ => sequent.left.push(f.clone()),
+        }
+    }
+    sequent
+}
+
+// Derivarea LK a secventului `goal` din tabloul închis pornit de la Γ, ¬Δ (None dacă tabloul are
+// ramuri deschise sau nu pornește de la `goal`). Formulele negate din Γ sunt traduse în dreapta,
+// așa că primesc la final câte un pas ¬L care le readuce în stânga, ca în secventul dat.
+pub fn from_tableau(goal: &Sequent, root: &TableauNode) -> Option<Proof> {
+    let expected: Vec<Formula> = goal.left.iter().cloned().chain(goal.right.iter().cloned().map(Formula::not)).collect();
+    if root.formulas != expected {
+        return None;
+    }
+    let mut proof = translate(root)?;
+    for f in goal.left.iter().rev() {
+        if let Formula::Not(inner) = f {
+            proof = negation_left(proof, f, inner)?;
+        }
+    }
+    // Aceleași formule ca în `goal`, eventual în altă ordine (schimbul e implicit)
+    proof.sequent = goal.clone();
+    Some(proof)
+}
+
+// Pasul ¬L care mută `inner` din dreapta în stânga, ca `negated`
+fn negation_left(proof: Proof, negated: &Formula, inner: &Formula) -> Option<Proof> {
+    let mut conclusion = proof.sequent.clone();
+    let pos = conclusion.right.iter().rposition(|f| f == inner)?;
+    conclusion.right.remove(pos);
+    conclusion.left.push(negated.clone());
+    Some(Proof { sequent: conclusion, rule: Rule::NotL, premises: vec![proof] })
+}
+
+// Fiecare regulă α/β devine regula LK a conectorului; o componentă negată pe care regula o pune
+// în stânga primește un pas ¬L, ca să ajungă în dreapta, acolo unde o așază traducerea nodului următor.
+fn translate(node: &TableauNode) -> Option<Proof> {
+    if node.status != NodeStatus::Closed {
+        return None;
+    }
+    let sequent = node_sequent(&node.formulas);
+    if node.children.is_empty() {
+        return Some(Proof { sequent, rule: Rule::Axiom, premises: Vec::new() });
+    }
+
+    // build_tableau extinde prima formulă care nu e literal
+    let principal = node.formulas.iter().find(|f| !f.is_literal())?;
+    let (rule, left_components): (Rule, &[&[bool]]) = match principal {
+        Formula::And(_, _) => (Rule::AndL, &[&[true, true]]),
+        Formula::Or(_, _) => (Rule::OrL, &[&[true], &[true]]),
+        Formula::Implies(_, _) => (Rule::ImpliesL, &[&[false], &[true]]),
+        Formula::Not(inner) => match &**inner {
+            Formula::Not(_) => (Rule::NotR, &[&[true]]),
+            Formula::And(_, _) => (Rule::AndR, &[&[false], &[false]]),
+            Formula::Or(_, _) => (Rule::OrR, &[&[false, false]]),
+            Formula::Implies(_, _) => (Rule::ImpliesR, &[&[true, false]]),
+            Formula::Var(_) => return None,
+        },
+        Formula::Var(_) => return None,
+    };
+
+    let kept = node.formulas.len() - 1;
+    let mut premises = Vec::new();
+    for (child, on_left) in node.children.iter().zip(left_components) {
+        let mut proof = translate(child)?;
+        let components = &child.formulas[kept..];
+        for (component, &left) in components.iter().zip(on_left.iter()).rev() {
+            if let (true, Formula::Not(inner)) = (left, component) {
+                proof = negation_left(proof, component, inner)?;
+            }
+        }
+        premises.push(proof);
+    }
+    Some(Proof { sequent, rule, premises })
+}
+
+impl Proof {
+    pub fn size(&self) -> usize {
+        1 + self.premises.iter().map(|p| p.size()).sum::<usize>()
+    }
+
+    // Arborele în stil Gentzen: premisele deasupra liniei, regula la dreapta ei
+    pub fn to_ascii(&self) -> String {
+        let (lines, _) = self.render();
+        lines.iter().map(|l| l.trim_end()).collect::<Vec<_>>().join("\n") + "\n"
+    }
+
+    fn render(&self) -> (Vec<String>, usize) {
+        let conclusion = self.sequent.to_string();
+        let conclusion_width = conclusion.chars().count();
+
+        // Premisele una lângă alta, aliniate jos
+        let blocks: Vec<(Vec<String>, usize)> = self.premises.iter().map(|p| p.render()).collect();
+        let height = blocks.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+        let premises_width = blocks.iter().map(|(_, w)| w).sum::<usize>() + 3 * blocks.len().saturating_sub(1);
+        let mut above = vec![String::new(); height];
+        for (i, (lines, width)) in blocks.iter().enumerate() {
+            let offset = height - lines.len();
+            for (row, line) in above.iter_mut().enumerate() {
+                if i > 0 { line.push_str("   "); }
+                let text = if row >= offset { lines[row - offset].as_str() } else { "" };
+                line.push_str(&format!("{:<w$}", text, w = width));
+            }
+        }
+
+        let bar = premises_width.max(conclusion_width);
+        let label = self.rule.name();
+        let width = bar + 1 + label.chars().count();
+        let center = |text: &str, len: usize| format!("{}{}", " ".repeat((bar - len) / 2), text);
+
+        let mut lines: Vec<String> = above.iter().map(|l| format!("{:<w$}", center(l, premises_width), w = width)).collect();
+        lines.push(format!("{} {}", "─".repeat(bar), label));
+        lines.push(format!("{:<w$}", center(&conclusion, conclusion_width), w = width));
+        (lines, width)
+    }
+
+    // Mediul `prooftree` din pachetul bussproofs
+    pub fn to_latex(&self) -> String {
+        let mut body = String::new();
+        self.latex_into(&mut body);
+        format!("\\begin{{prooftree}}\n{}\\end{{prooftree}}\n", body)
+    }
+
+    fn latex_into(&self, out: &mut String) {
+        if self.premises.is_empty() {
+            out.push_str("\\AxiomC{}\n");
+        }
+        for p in &self.premises {
+            p.latex_into(out);
+        }
+        let inference = match self.premises.len() {
+            0 | 1 => "UnaryInfC",
+            2 => "BinaryInfC",
+            _ => "TrinaryInfC",
+        };
+        out.push_str(&format!("\\RightLabel{{\\scriptsize ${}$}}\n\\{}{{${}$}}\n", self.rule.latex(), inference, self.sequent.latex()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tableau::solver::build_tableau;
+
+    fn sorted(formulas: &[Formula]) -> Vec<String> {
+        let mut v: Vec<String> = formulas.iter().map(|f| f.to_string()).collect();
+        v.sort();
+        v
+    }
+
+    fn same(a: &Sequent, b: &Sequent) -> bool {
+        sorted(&a.left) == sorted(&b.left) && sorted(&a.right) == sorted(&b.right)
+    }
+
+    // Fiecare pas e o aplicare corectă a regulii declarate, pe o formulă din concluzie
+    fn assert_derivation(proof: &Proof) {
+        let s = &proof.sequent;
+        if proof.rule == Rule::Axiom {
+            assert!(proof.premises.is_empty() && s.is_axiom(), "{} nu e axiomă", s);
+            return;
+        }
+        let applications = (0..s.left.len()).map(|i| (true, i)).chain((0..s.right.len()).map(|i| (false, i)));
+        let matches = applications.filter_map(|(on_left, i)| s.premises(on_left, i)).any(|(rule, premises)| {
+            rule == proof.rule && premises.len() == proof.premises.len()
+                && premises.iter().zip(&proof.premises).all(|(p, q)| same(p, &q.sequent))
+        });
+        assert!(matches, "{} nu se obține din premise cu {}", s, proof.rule.name());
+        proof.premises.iter().for_each(assert_derivation);
+    }
+
+    #[test]
+    fn valid_sequents_are_proved() {
+        for text in ["⊢ p -> (q -> p)", "p -> q, q -> r |- p -> r", "⊢ !(p & q) -> (!p | !q)", "p | q, !p ⊢ q"] {
+            let proof = Sequent::parse(text).prove().unwrap_or_else(|s| panic!("{} are contraexemplul {}", text, s));
+            assert_derivation(&proof);
+        }
+    }
+
+    #[test]
+    fn invalid_sequents_give_a_counterexample() {
+        let leaf = Sequent::parse("p -> q ⊢ q -> p").prove().expect_err("Secventul nu e valid");
+        assert!(leaf.left.iter().chain(&leaf.right).all(|f| matches!(f, Formula::Var(_))));
+        assert!(!leaf.is_axiom());
+        // q adevărat, p fals
+        assert!(leaf.left.contains(&Formula::var("q")) && !leaf.left.contains(&Formula::var("p")));
+        assert!(leaf.right.contains(&Formula::var("p")));
+    }
+
+    // Tabloul pornit de la Γ, ¬Δ, ca în `lc lk --from-tableau`
+    fn tableau_proof(text: &str) -> (Sequent, Option<Proof>) {
+        let goal = Sequent::parse(text);
+        let formulas = goal.left.iter().cloned().chain(goal.right.iter().cloned().map(Formula::not)).collect();
+        let proof = from_tableau(&goal, &build_tableau(formulas));
+        (goal, proof)
+    }
+
+    #[test]
+    fn closed_tableau_becomes_an_lk_proof() {
+        let (goal, proof) = tableau_proof("⊢ (p -> q) -> (!q -> !p)");
+        let proof = proof.expect("Tabloul e închis");
+        assert!(same(&proof.sequent, &goal));
+        assert_derivation(&proof);
+
+        assert!(tableau_proof("⊢ p -> q").1.is_none());
+    }
+
+    #[test]
+    fn negated_antecedents_stay_on_the_left() {
+        // ¬p din stânga ajunge în dreapta la traducere; pasul ¬L final îl readuce
+        for text in ["!p, p | q |- q", "!!p |- p", "!p, !q |- !(p | q)", "!p |- p -> q"] {
+            let (goal, proof) = tableau_proof(text);
+            let proof = proof.unwrap_or_else(|| panic!("{}: tabloul e închis", text));
+            assert!(same(&proof.sequent, &goal), "{}: concluzia e {}", text, proof.sequent);
+            assert_derivation(&proof);
+        }
+        let (goal, proof) = tableau_proof("!p, p | q |- q");
+        let proof = proof.unwrap();
+        assert_eq!(proof.rule, Rule::NotL);
+        assert_eq!(proof.sequent.to_string(), goal.to_string());
+    }
+
+    #[test]
+    fn rendering() {
+        let proof = Sequent::parse("p ⊢ p | q").prove().unwrap();
+        assert_eq!(proof.size(), 2);
+        assert_eq!(proof.to_ascii(), "──────── Ax\np ⊢ p, q\n─────────── ∨R\np ⊢ (p ∨ q)\n");
+        let tex = proof.to_latex();
+        assert!(tex.starts_with("\\begin{prooftree}\n\\AxiomC{}\n"));
+        assert!(tex.contains("\\UnaryInfC{$p \\vdash p \\lor q$}"));
+    }
+}
@@ -0,0 +1,405 @@
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+
+use crate::formula::Formula;
+use super::node::{Annotation, NodeStatus, TableauNode};
+
+// Ce afirmă certificatul; determină mulțimea de formule din rădăcina tabloului
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Claim {
+    // Mulțimea de formule este nesatisfiabilă (tabloul pornește chiar de la ea)
+    Unsatisfiable(Vec<Formula>),
+    // Formula e tautologie: tabloul pornește de la negația ei
+    Valid(Formula),
+    // Premisele implică concluzia: tabloul pornește de la premise și negația concluziei
+    Entails { premises: Vec<Formula>, conclusion: Formula },
+}
+
+impl Claim {
+    pub fn root_formulas(&self) -> Vec<Formula> {
+        match self {
+            Claim::Unsatisfiable(formulas) => formulas.clone(),
+            Claim::Valid(f) => vec![Formula::not(f.clone())],
+            Claim::Entails { premises, conclusion } => premises.iter().cloned().chain([Formula::not(conclusion.clone())]).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Claim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |fs: &[Formula]| fs.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+        match self {
+            Claim::Unsatisfiable(formulas) => write!(f, "{{{}}} este nesatisfiabilă", list(formulas)),
+            Claim::Valid(formula) => write!(f, "{} este tautologie", formula),
+            Claim::Entails { premises, conclusion } => write!(f, "{} ⊨ {}", list(premises), conclusion),
+        }
+    }
+}
+
+// Certificatul: afirmația și tabloul adnotat care o justifică
+#[derive(Debug, Clone)]
+pub struct Certificate {
+    pub claim: Claim,
+    pub tableau: TableauNode,
+}
+
+// Formatul JSON: nodurile tabloului într-o listă plată, în preordine, fiecare cu indicele
+// părintelui. Fișierul are aceeași adâncime oricât de adânc ar fi tabloul.
+#[derive(Serialize, Deserialize)]
+struct CertificateFile {
+    claim: Claim,
+    nodes: Vec<FlatNode>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FlatNode {
+    parent: Option<usize>,
+    formulas: Vec<Formula>,
+    status: NodeStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    annotation: Option<Annotation>,
+}
+
+// Tabloul în preordine (cu o stivă, fără recursivitate)
+fn flatten(root: &TableauNode) -> Vec<FlatNode> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![(root, None)];
+    while let Some((node, parent)) = stack.pop() {
+        let index = nodes.len();
+        nodes.push(FlatNode {
+            parent,
+            formulas: node.formulas.clone(),
+            status: node.status.clone(),
+            annotation: node.annotation.clone(),
+        });
+        stack.extend(node.children.iter().rev().map(|child| (child, Some(index))));
+    }
+    nodes
+}
+
+// Arborele la loc: fiecare nod e mutat în părintele lui de la ultimul la primul, așa că
+// un nod își are deja toți copiii când ajunge la rândul lui
+fn unflatten(flat: Vec<FlatNode>) -> Result<TableauNode, String> {
+    let mut parents = Vec::new();
+    let mut nodes: Vec<Option<TableauNode>> = Vec::new();
+    for (i, node) in flat.into_iter().enumerate() {
+        match (i, node.parent) {
+            (0, None) => {},
+            (0, Some(_)) => return Err("rădăcina (primul nod) nu poate avea părinte".to_string()),
+            (_, Some(p)) if p < i => parents.push(p),
+            (_, Some(p)) => return Err(format!("nodul {} are părintele {}, care nu îl precede", i, p)),
+            (_, None) => return Err(format!("nodul {} nu are părinte", i)),
+        }
+        nodes.push(Some(TableauNode { formulas: node.formulas, children: Vec::new(), status: node.status, annotation: node.annotation }));
+    }
+    if nodes.is_empty() {
+        return Err("tabloul nu are noduri".to_string());
+    }
+
+    for i in (1..nodes.len()).rev() {
+        let mut node = nodes[i].take().expect("Nod mutat de două ori");
+        node.children.reverse();
+        nodes[parents[i - 1]].as_mut().expect("Părintele e mutat după copii").children.push(node);
+    }
+    let mut root = nodes[0].take().expect("Rădăcina");
+    root.children.reverse();
+    Ok(root)
+}
+
+// Prima greșeală: drumul de la rădăcină (indicii copiilor, de la 1) și explicația
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateError {
+    pub path: Vec<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path: Vec<String> = std::iter::once("rădăcina".to_string()).chain(self.path.iter().map(|i| i.to_string())).collect();
+        write!(f, "nodul {}: {}", path.join(" → "), self.message)
+    }
+}
+
+// Regulile α/β scrise din nou aici, intenționat: verificatorul nu folosește codul care
+// construiește tablourile. Întoarce (este β, componentele fiecărei ramuri).
+fn components(f: &Formula) -> Option<(bool, Vec<Vec<Formula>>)> {
+    let neg = |x: &Formula| Formula::not(x.clone());
+    match f {
+        Formula::Var(_) => None,
+        Formula::And(a, b) => Some((false, vec![vec![(**a).clone(), (**b).clone()]])),
+        Formula::Or(a, b) => Some((true, vec![vec![(**a).clone()], vec![(**b).clone()]])),
+        Formula::Implies(a, b) => Some((true, vec![vec![neg(a)], vec![(**b).clone()]])),
+        Formula::Not(inner) => match &**inner {
+            Formula::Var(_) => None,
+            Formula::Not(a) => Some((false, vec![vec![(**a).clone()]])),
+            Formula::Or(a, b) => Some((false, vec![vec![neg(a), neg(b)]])),
+            Formula::Implies(a, b) => Some((false, vec![vec![(**a).clone(), neg(b)]])),
+            Formula::And(a, b) => Some((true, vec![vec![neg(a)], vec![neg(b)]])),
+        },
+    }
+}
+
+fn count(formulas: &[Formula], f: &Formula) -> usize {
+    formulas.iter().filter(|x| *x == f).count()
+}
+
+// Copilul conține componentele, păstrează restul formulelor părintelui (formula descompusă
+// poate lipsi) și nu adaugă nimic altceva
+fn child_matches(parent: &[Formula], expanded: &Formula, parts: &[Formula], child: &[Formula]) -> Result<(), String> {
+    for part in parts {
+        if count(child, part) < count(parts, part) {
+            return Err(format!("lipsește componenta {}", part));
+        }
+    }
+    for f in parent {
+        let needed = count(parent, f) - if f == expanded { 1 } else { 0 };
+        if count(child, f) < needed {
+            return Err(format!("formula {} a dispărut de pe ramură", f));
+        }
+    }
+    if let Some(extra) = child.iter().find(|f| !parent.contains(f) && !parts.contains(f)) {
+        return Err(format!("formula {} nu provine din regulă", extra));
+    }
+    Ok(())
+}
+
+fn fail<T>(path: &[usize], message: String) -> Result<T, CertificateError> {
+    Err(CertificateError { path: path.to_vec(), message })
+}
+
+// Verifică nodul și subarborele lui; întoarce dacă toate ramurile de sub el sunt închise
+fn check_node(node: &TableauNode, path: &mut Vec<usize>) -> Result<bool, CertificateError> {
+    if node.children.is_empty() {
+        return match (&node.status, &node.annotation) {
+            (NodeStatus::Closed, Some(Annotation::Closure { left, right })) => {
+                if !node.formulas.contains(left) || !node.formulas.contains(right) {
+                    return fail(path, format!("perechea de închidere {}, {} nu se află pe ramură", left, right));
+                }
+                let contradictory = matches!(right, Formula::Not(x) if **x == *left) || matches!(left, Formula::Not(x) if **x == *right);
+                if !contradictory {
+                    return fail(path, format!("{} și {} nu sunt contradictorii", left, right));
+                }
+                Ok(true)
+            },
+            (NodeStatus::Closed, _) => fail(path, "ramura e marcată închisă fără o pereche de formule contradictorii".to_string()),
+            (NodeStatus::Open | NodeStatus::Unknown, Some(Annotation::Closure { .. })) => fail(path, "ramura are o pereche de închidere, dar nu e marcată închisă".to_string()),
+            // O ramură neexplorată (oprită de o limită) nu închide tabloul, dar nici nu e greșită
+            (NodeStatus::Open | NodeStatus::Unknown, _) => Ok(false),
+            (NodeStatus::Intermediate, _) => fail(path, "o frunză nu poate fi nod intermediar".to_string()),
+        };
+    }
+
+    let Some(Annotation::Expansion { formula, rule }) = &node.annotation else {
+        return fail(path, "nodul are copii, dar nu spune ce formulă a fost descompusă".to_string());
+    };
+    if !node.formulas.contains(formula) {
+        return fail(path, format!("formula descompusă {} nu se află pe ramură", formula));
+    }
+    let Some((beta, branches)) = components(formula) else {
+        return fail(path, format!("{} este un literal și nu se descompune", formula));
+    };
+    let declared = rule.trim().to_lowercase();
+    if (beta && (declared.starts_with('α') || declared.starts_with("alpha"))) || (!beta && (declared.starts_with('β') || declared.starts_with("beta"))) {
+        return fail(path, format!("regula declarată este „{}”, dar {} se descompune cu o regulă {}", rule, formula, if beta { "β" } else { "α" }));
+    }
+    if node.children.len() != branches.len() {
+        return fail(path, format!("regula pentru {} produce {} ramuri, nu {}", formula, branches.len(), node.children.len()));
+    }
+
+    // Pentru β, ramurile pot apărea în orice ordine
+    let orders: Vec<Vec<usize>> = if branches.len() == 2 { vec![vec![0, 1], vec![1, 0]] } else { vec![vec![0]] };
+    let mut first_error = None;
+    let matched = orders.iter().any(|order| {
+        order.iter().zip(&node.children).all(|(&b, child)| {
+            match child_matches(&node.formulas, formula, &branches[b], &child.formulas) {
+                Ok(()) => true,
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                    false
+                },
+            }
+        })
+    });
+    if !matched {
+        return fail(path, format!("descompunerea lui {} e greșită: {}", formula, first_error.unwrap_or_default()));
+    }
+
+    let mut closed = true;
+    for (i, child) in node.children.iter().enumerate() {
+        path.push(i + 1);
+        closed &= check_node(child, path)?;
+        path.pop();
+    }
+    match (&node.status, closed) {
+        (NodeStatus::Closed, false) => fail(path, "nodul e marcat închis, dar are ramuri deschise".to_string()),
+        (NodeStatus::Open | NodeStatus::Intermediate | NodeStatus::Unknown, true) => fail(path, "toate ramurile de sub nod sunt închise, dar nodul nu e marcat închis".to_string()),
+        _ => Ok(closed),
+    }
+}
+
+impl Certificate {
+    pub fn new(claim: Claim, tableau: TableauNode) -> Self {
+        Certificate { claim, tableau }
+    }
+
+    pub fn to_json(&self) -> String {
+        let file = CertificateFile { claim: self.claim.clone(), nodes: flatten(&self.tableau) };
+        serde_json::to_string_pretty(&file).expect("Serializarea certificatului")
+    }
+
+    pub fn from_json(text: &str) -> Result<Certificate, String> {
+        let file: CertificateFile = serde_json::from_str(text).map_err(|e| format!("Certificat invalid: {}", e))?;
+        let tableau = unflatten(file.nodes).map_err(|e| format!("Certificat invalid: {}", e))?;
+        Ok(Certificate { claim: file.claim, tableau })
+    }
+
+    pub fn read_file(path: &str) -> Result<Certificate, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Nu pot citi fișierul {}: {}", path, e))?;
+        Certificate::from_json(&text)
+    }
+
+    // Ok(true) dacă tabloul e corect și închis (afirmația e demonstrată), Ok(false) dacă e corect dar deschis
+    pub fn check(&self) -> Result<bool, CertificateError> {
+        let expected = self.claim.root_formulas();
+        let root = &self.tableau.formulas;
+        if expected.iter().any(|f| count(root, f) < count(&expected, f)) || root.iter().any(|f| !expected.contains(f)) {
+            let shown = expected.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ");
+            return fail(&[], format!("rădăcina trebuie să conțină exact formulele {}", shown));
+        }
+        check_node(&self.tableau, &mut Vec::new())
+    }
+
+    pub fn print_check(&self) -> bool {
+        println!("Afirmație: {}", self.claim.to_string().yellow().bold());
+        match self.check() {
+            Ok(true) => {
+                println!("{}", "Certificat valid: toate regulile și închiderile sunt corecte, tabloul e închis.".green().bold());
+                true
+            },
+            Ok(false) => {
+                println!("{}", "Tabloul e corect, dar are ramuri deschise sau neexplorate: afirmația nu este demonstrată.".yellow().bold());
+                false
+            },
+            Err(e) => {
+                println!("{} {}", "Certificat invalid la".red().bold(), e);
+                false
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+    use crate::tableau::solver::build_tableau;
+
+    fn certificate(claim: Claim) -> Certificate {
+        let tableau = build_tableau(claim.root_formulas());
+        Certificate::new(claim, tableau)
+    }
+
+    fn peirce() -> Certificate {
+        certificate(Claim::Valid(parse_formula("((p -> q) -> p) -> p")))
+    }
+
+    #[test]
+    fn generated_certificates_are_valid() {
+        assert_eq!(peirce().check(), Ok(true));
+        let entails = Claim::Entails { premises: vec![parse_formula("p -> q"), parse_formula("q -> r")], conclusion: parse_formula("p -> r") };
+        assert_eq!(certificate(entails).check(), Ok(true));
+        // Tablou corect, dar deschis: afirmația nu e demonstrată
+        assert_eq!(certificate(Claim::Valid(parse_formula("p -> q"))).check(), Ok(false));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let cert = peirce();
+        let back = Certificate::from_json(&cert.to_json()).unwrap();
+        assert_eq!(back.claim, cert.claim);
+        assert_eq!(back.check(), Ok(true));
+        assert!(Certificate::from_json("{\"claim\": 1}").unwrap_err().starts_with("Certificat invalid"));
+    }
+
+    #[test]
+    fn deep_tableau_round_trip() {
+        // p1 ∧ ... ∧ p80 → p1: un lanț de 80 de reguli α, mult peste limita de imbricare a parserului JSON
+        let conjunction = (1..=80).map(|i| format!("p{}", i)).collect::<Vec<_>>().join(" & ");
+        let cert = certificate(Claim::Valid(parse_formula(&format!("{} -> p1", conjunction))));
+        let json = cert.to_json();
+        let back = Certificate::from_json(&json).unwrap();
+        assert_eq!(back.claim, cert.claim);
+        assert_eq!(back.check(), Ok(true));
+        assert_eq!(back.to_json(), json);
+    }
+
+    #[test]
+    fn malformed_node_lists_are_rejected() {
+        let claim = "\"claim\": {\"Valid\": \"(p → p)\"}";
+        let node = |parent: &str| format!("{{\"parent\": {}, \"formulas\": [\"¬(p → p)\"], \"status\": \"Open\"}}", parent);
+        let json = |nodes: &[String]| format!("{{{}, \"nodes\": [{}]}}", claim, nodes.join(", "));
+        assert!(Certificate::from_json(&json(&[node("null")])).is_ok());
+        assert!(Certificate::from_json(&json(&[])).unwrap_err().contains("nu are noduri"));
+        assert!(Certificate::from_json(&json(&[node("null"), node("1")])).unwrap_err().contains("nu îl precede"));
+        assert!(Certificate::from_json(&json(&[node("null"), node("null")])).unwrap_err().contains("nu are părinte"));
+        let bad_formula = json(&[node("null")]).replace("¬(p → p)", "p ∧");
+        assert!(Certificate::from_json(&bad_formula).unwrap_err().contains("Sintaxă invalidă"));
+    }
+
+    #[test]
+    fn tampered_claim_is_rejected() {
+        let mut cert = peirce();
+        cert.claim = Claim::Valid(parse_formula("((p -> q) -> q) -> q"));
+        let e = cert.check().unwrap_err();
+        assert!(e.path.is_empty() && e.message.starts_with("rădăcina trebuie"));
+    }
+
+    #[test]
+    fn tampered_child_is_rejected() {
+        let mut cert = peirce();
+        let child = &mut cert.tableau.children[0];
+        child.formulas.push(parse_formula("z"));
+        // Greșeala e semnalată la părinte, a cărui descompunere nu mai corespunde regulii
+        let e = cert.check().unwrap_err();
+        assert!(e.path.is_empty());
+        assert!(e.to_string().contains("z nu provine din regulă"), "{}", e);
+    }
+
+    fn first_closed_leaf(node: &mut TableauNode) -> Option<&mut TableauNode> {
+        if node.children.is_empty() {
+            return (node.status == NodeStatus::Closed).then_some(node);
+        }
+        node.children.iter_mut().find_map(first_closed_leaf)
+    }
+
+    #[test]
+    fn tampered_closure_is_rejected() {
+        let mut cert = peirce();
+        let leaf = first_closed_leaf(&mut cert.tableau).unwrap();
+        let Some(Annotation::Closure { right, .. }) = &mut leaf.annotation else { panic!("frunză închisă fără pereche") };
+        *right = parse_formula("!z");
+        assert!(cert.check().unwrap_err().message.contains("nu se află pe ramură"));
+
+        let mut cert = peirce();
+        first_closed_leaf(&mut cert.tableau).unwrap().annotation = None;
+        assert!(cert.check().unwrap_err().message.contains("fără o pereche"));
+    }
+
+    #[test]
+    fn open_branch_marked_closed_is_rejected() {
+        let mut cert = certificate(Claim::Valid(parse_formula("p -> q")));
+        cert.tableau.children[0].status = NodeStatus::Closed;
+        cert.tableau.status = NodeStatus::Closed;
+        assert!(cert.check().is_err());
+    }
+
+    #[test]
+    fn wrong_rule_name_is_rejected() {
+        let mut cert = peirce();
+        let Some(Annotation::Expansion { rule, .. }) = &mut cert.tableau.annotation else { panic!("rădăcina trebuie descompusă") };
+        *rule = "β".to_string();
+        assert!(cert.check().unwrap_err().message.contains("regula declarată"));
+    }
+}
@@ -0,0 +1,345 @@
+use colored::*;
+
+use crate::formula::Formula;
+use crate::ui;
+use super::rules::{self, RuleKind};
+use super::solver::build_tableau_with_limits;
+use super::stats::{Limits, Verdict};
+
+// Un nod al tabloului construit de utilizator: formulele adăugate aici, regula aplicată
+// (formula descompusă și numele regulii) și, pentru ramurile închise, perechea contradictorie
+#[derive(Debug, Clone)]
+struct ManualNode {
+    formulas: Vec<Formula>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    rule: Option<(Formula, &'static str)>,
+    closed: Option<(Formula, Formula)>,
+}
+
+// Tabloul manual: utilizatorul alege ramura și formula, instrumentul aplică regula și verifică închiderile
+#[derive(Debug, Clone)]
+pub struct ManualTableau {
+    nodes: Vec<ManualNode>,
+    history: Vec<Vec<ManualNode>>,
+    pub hints: usize,
+}
+
+impl ManualTableau {
+    pub fn new(formulas: Vec<Formula>) -> Self {
+        let root = ManualNode { formulas, parent: None, children: Vec::new(), rule: None, closed: None };
+        ManualTableau { nodes: vec![root], history: Vec::new(), hints: 0 }
+    }
+
+    // Frunzele, de la stânga la dreapta; ramura k (de la 1) se termină în frunza k
+    fn leaves(&self) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![0];
+        while let Some(n) = stack.pop() {
+            if self.nodes[n].children.is_empty() {
+                leaves.push(n);
+            }
+            stack.extend(self.nodes[n].children.iter().rev());
+        }
+        leaves
+    }
+
+    fn path(&self, leaf: usize) -> Vec<usize> {
+        let mut path = vec![leaf];
+        while let Some(p) = self.nodes[*path.last().expect("Drum nevid")].parent {
+            path.push(p);
+        }
+        path.reverse();
+        path
+    }
+
+    fn branch_formulas(&self, leaf: usize) -> Vec<Formula> {
+        self.path(leaf).iter().flat_map(|&n| self.nodes[n].formulas.iter().cloned()).collect()
+    }
+
+    // Formulele deja descompuse pe ramură
+    fn used(&self, leaf: usize) -> Vec<Formula> {
+        self.path(leaf).iter().filter_map(|&n| self.nodes[n].rule.as_ref().map(|(f, _)| f.clone())).collect()
+    }
+
+    fn open_branch(&self, branch: usize) -> Result<usize, String> {
+        let leaves = self.leaves();
+        let leaf = *branch.checked_sub(1).and_then(|i| leaves.get(i)).ok_or(format!("Ramura {} nu există (sunt {}).", branch, leaves.len()))?;
+        if self.nodes[leaf].closed.is_some() {
+            return Err(format!("Ramura {} este deja închisă.", branch));
+        }
+        Ok(leaf)
+    }
+
+    fn formula_at(&self, leaf: usize, index: usize) -> Result<Formula, String> {
+        let formulas = self.branch_formulas(leaf);
+        index.checked_sub(1).and_then(|i| formulas.get(i)).cloned()
+            .ok_or(format!("Formula {} nu există pe ramură (are {} formule).", index, formulas.len()))
+    }
+
+    // Descompune formula `index` de pe ramura `branch` (numerotate de la 1); întoarce numele regulii
+    pub fn expand(&mut self, branch: usize, index: usize) -> Result<&'static str, String> {
+        let leaf = self.open_branch(branch)?;
+        let f = self.formula_at(leaf, index)?;
+        if self.used(leaf).contains(&f) {
+            return Err(format!("{} a fost deja descompusă pe această ramură.", f));
+        }
+        let expansion = rules::expand(&f).ok_or(format!("{} este un literal și nu se mai descompune.", f))?;
+
+        self.history.push(self.nodes.clone());
+        for parts in expansion.branches {
+            let child = self.nodes.len();
+            self.nodes.push(ManualNode { formulas: parts, parent: Some(leaf), children: Vec::new(), rule: None, closed: None });
+            self.nodes[leaf].children.push(child);
+        }
+        self.nodes[leaf].rule = Some((f, expansion.name));
+        Ok(expansion.name)
+    }
+
+    // Închide ramura dacă formulele `i` și `j` sunt contradictorii (A și ¬A)
+    pub fn close(&mut self, branch: usize, i: usize, j: usize) -> Result<(), String> {
+        let leaf = self.open_branch(branch)?;
+        let (a, b) = (self.formula_at(leaf, i)?, self.formula_at(leaf, j)?);
+        if !a.is_contradiction_with(&b) {
+            return Err(format!("{} și {} nu sunt contradictorii: ramura nu se poate închide.", a, b));
+        }
+        self.history.push(self.nodes.clone());
+        self.nodes[leaf].closed = Some((a, b));
+        Ok(())
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(nodes) => {
+                self.nodes = nodes;
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn open_leaves(&self) -> Vec<(usize, usize)> {
+        self.leaves().into_iter().enumerate()
+            .filter(|&(_, leaf)| self.nodes[leaf].closed.is_none())
+            .map(|(i, leaf)| (i + 1, leaf))
+            .collect()
+    }
+
+    // Formulele care se mai pot descompune pe ramură
+    fn pending(&self, leaf: usize) -> Vec<(usize, Formula, RuleKind)> {
+        let used = self.used(leaf);
+        self.branch_formulas(leaf).into_iter().enumerate()
+            .filter(|(_, f)| !used.contains(f))
+            .filter_map(|(i, f)| rules::expand(&f).map(|e| (i + 1, f, e.kind)))
+            .collect()
+    }
+
+    // Sugestia următoarei mutări: întâi închiderile, apoi regulile α (nu ramifică), apoi β
+    fn suggestion(&self) -> Option<String> {
+        for (branch, leaf) in self.open_leaves() {
+            if let Some((i, j)) = rules::find_contradiction(&self.branch_formulas(leaf)) {
+                return Some(format!("Ramura {} conține o contradicție: închide-o cu 'i {} {} {}'.", branch, branch, i + 1, j + 1));
+            }
+        }
+        for kind in [RuleKind::Alpha, RuleKind::Beta] {
+            for (branch, leaf) in self.open_leaves() {
+                if let Some((i, f, _)) = self.pending(leaf).into_iter().find(|(_, _, k)| *k == kind) {
+                    let why = if kind == RuleKind::Alpha { "regulile α nu ramifică, aplică-le primele" } else { "nu mai sunt reguli α" };
+                    return Some(format!("Descompune {} pe ramura {} ('e {} {}'): {}.", f, branch, branch, i, why));
+                }
+            }
+        }
+        None
+    }
+
+    // Doar indiciile care propun o mutare sunt numărate
+    pub fn hint(&mut self) -> String {
+        match self.suggestion() {
+            Some(text) => {
+                self.hints += 1;
+                text
+            },
+            None => "Nu mai este nimic de făcut.".to_string(),
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.open_leaves().is_empty()
+    }
+
+    // O ramură deschisă, complet descompusă și fără contradicții: tabloul nu se mai poate închide
+    pub fn saturated_branch(&self) -> Option<usize> {
+        self.open_leaves().into_iter()
+            .find(|&(_, leaf)| self.pending(leaf).is_empty() && rules::find_contradiction(&self.branch_formulas(leaf)).is_none())
+            .map(|(branch, _)| branch)
+    }
+
+    pub fn rule_applications(&self) -> usize {
+        self.nodes.iter().filter(|n| n.rule.is_some()).count()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn print(&self) {
+        self.print_node(0, String::new(), true);
+        println!();
+        for (branch, leaf) in self.open_leaves() {
+            let used = self.used(leaf);
+            println!("{}", format!("Ramura {}:", branch).cyan().bold());
+            for (i, f) in self.branch_formulas(leaf).iter().enumerate() {
+                let text = format!("   {:>2}. {}", i + 1, f);
+                if used.contains(f) || f.is_literal() { println!("{}", text.dimmed()); } else { println!("{}", text); }
+            }
+        }
+    }
+
+    fn print_node(&self, n: usize, prefix: String, is_last: bool) {
+        let node = &self.nodes[n];
+        let marker = if is_last { "└── " } else { "├── " };
+        let forms = node.formulas.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ");
+        let status = match (&node.closed, node.children.is_empty()) {
+            (Some((a, b)), _) => format!(" [❌ ÎNCHIS: {}, {}]", a, b).red().bold(),
+            (None, true) => format!(" [ramura {}]", self.leaves().iter().position(|&l| l == n).map_or(0, |i| i + 1)).green(),
+            (None, false) => "".normal(),
+        };
+        let rule = node.rule.as_ref().map(|(f, name)| format!("  ⟵ {} ({})", f, name).dimmed().to_string()).unwrap_or_default();
+        println!("{}{}{}{}{}", prefix, marker, forms, status, rule);
+
+        let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+        for (i, &child) in node.children.iter().enumerate() {
+            self.print_node(child, child_prefix.clone(), i == node.children.len() - 1);
+        }
+    }
+}
+
+fn print_help() {
+    println!("{}", "Comenzi:".bold());
+    println!("  e <ramură> <formulă>     descompune formula (numerele din lista ramurii)");
+    println!("  i <ramură> <f1> <f2>     închide ramura: f1 și f2 sunt contradictorii");
+    println!("  h                        indiciu");
+    println!("  u                        anulează ultima mutare");
+    println!("  a                        afișează arborele");
+    println!("  q                        renunță");
+}
+
+// Sesiunea interactivă; la final, dimensiunea tabloului e comparată cu cea a tabloului automat
+pub fn run(formulas: Vec<Formula>) {
+    let mut tableau = ManualTableau::new(formulas.clone());
+    print_help();
+
+    loop {
+        println!();
+        tableau.print();
+        if tableau.is_closed() {
+            println!("\n{}", "Toate ramurile sunt închise: tabloul este ÎNCHIS (mulțimea e nesatisfiabilă).".green().bold());
+            break;
+        }
+        if let Some(branch) = tableau.saturated_branch() {
+            println!("\n{}", format!("Ramura {} e complet descompusă și necontradictorie: tabloul nu se poate închide (mulțimea e satisfiabilă).", branch).yellow().bold());
+            break;
+        }
+
+        let line = ui::read_line("\nMutare > ");
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if matches!(parts.first().map(|p| p.to_lowercase()).as_deref(), None | Some("q" | "renunta" | "renunță")) {
+            return;
+        }
+        let numbers: Vec<usize> = parts.iter().skip(1).filter_map(|p| p.parse().ok()).collect();
+        let result = match (parts.first().map(|p| p.to_lowercase()).as_deref(), numbers.as_slice()) {
+            (Some("e" | "extinde"), &[branch, index]) => tableau.expand(branch, index).map(|name| format!("Regula aplicată: {}", name)),
+            (Some("i" | "inchide" | "închide"), &[branch, i, j]) => tableau.close(branch, i, j).map(|_| format!("Ramura {} închisă.", branch)),
+            (Some("h" | "indiciu"), _) => Ok(tableau.hint()),
+            (Some("u" | "anuleaza" | "anulează"), _) => Ok(if tableau.undo() { "Mutare anulată." } else { "Nu există mutări de anulat." }.to_string()),
+            (Some("a" | "arbore"), _) => continue,
+            _ => {
+                print_help();
+                continue;
+            },
+        };
+        match result {
+            Ok(message) => println!("{}", message.green()),
+            Err(message) => println!("{} {}", "Mutare respinsă:".red().bold(), message),
+        }
+    }
+
+    // Aceleași limite ca tabloul automat din meniu: o formulă uriașă nu blochează comparația
+    let automatic = build_tableau_with_limits(formulas, &Limits::interactive()).1;
+    println!("\n{}", "=== COMPARAȚIE CU TABLOUL AUTOMAT ===".purple().bold());
+    println!("{:<22} {:>8} {:>10}", "", "noduri", "reguli");
+    println!("{:<22} {:>8} {:>10}", "Tabloul tău", tableau.node_count(), tableau.rule_applications());
+    println!("{:<22} {:>8} {:>10}", "Tabloul automat", automatic.nodes, automatic.rule_applications);
+    if let Some(limit) = automatic.limit {
+        let verdict = if automatic.verdict() == Verdict::Unknown { " și nu are verdict" } else { "" };
+        println!("{}", format!("Tabloul automat a fost oprit (s-a atins {}){}: valorile lui sunt parțiale.", limit, verdict).yellow());
+    }
+    if tableau.hints > 0 {
+        println!("{}", format!("Indicii folosite: {}", tableau.hints).dimmed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    fn tableau(texts: &[&str]) -> ManualTableau {
+        ManualTableau::new(texts.iter().map(|t| parse_formula(t)).collect())
+    }
+
+    #[test]
+    fn closing_modus_ponens_by_hand() {
+        let mut t = tableau(&["p -> q", "p", "!q"]);
+        assert_eq!(t.expand(1, 1), Ok("β: A → B"));
+        // Ramura 1: p → q, p, ¬q, ¬p; ramura 2: ..., q
+        t.close(1, 2, 4).unwrap();
+        assert!(!t.is_closed());
+        t.close(2, 3, 4).unwrap();
+        assert!(t.is_closed());
+        assert_eq!((t.node_count(), t.rule_applications()), (3, 1));
+
+        let automatic = build_tableau_with_limits(vec![parse_formula("p -> q"), parse_formula("p"), parse_formula("!q")], &Limits::interactive()).1;
+        assert_eq!((automatic.nodes, automatic.rule_applications), (3, 1));
+    }
+
+    #[test]
+    fn illegal_moves_are_rejected() {
+        let mut t = tableau(&["p & q", "!p"]);
+        assert!(t.expand(1, 2).unwrap_err().contains("literal"));
+        assert!(t.expand(2, 1).unwrap_err().contains("nu există"));
+        assert!(t.expand(1, 5).unwrap_err().contains("nu există pe ramură"));
+        assert!(t.close(1, 1, 2).unwrap_err().contains("nu sunt contradictorii"));
+        t.expand(1, 1).unwrap();
+        assert!(t.expand(1, 1).unwrap_err().contains("deja descompusă"));
+        t.close(1, 2, 3).unwrap();
+        assert!(t.close(1, 2, 3).unwrap_err().contains("deja închisă"));
+    }
+
+    #[test]
+    fn undo_restores_the_previous_tableau() {
+        let mut t = tableau(&["p | q"]);
+        assert!(!t.undo());
+        t.expand(1, 1).unwrap();
+        assert_eq!(t.node_count(), 3);
+        assert!(t.undo());
+        assert_eq!((t.node_count(), t.rule_applications()), (1, 0));
+    }
+
+    #[test]
+    fn hints_prefer_closures_then_alpha_rules() {
+        let mut t = tableau(&["p | q", "r & s", "t", "!t"]);
+        assert!(t.hint().starts_with("Ramura 1 conține o contradicție"));
+        let mut t = tableau(&["p | q", "r & s"]);
+        assert!(t.hint().contains("'e 1 2'"));
+        assert_eq!(t.hints, 1);
+    }
+
+    #[test]
+    fn only_useful_hints_are_counted() {
+        let mut t = tableau(&["p", "q"]);
+        assert_eq!(t.saturated_branch(), Some(1));
+        assert_eq!(t.hint(), "Nu mai este nimic de făcut.");
+        assert_eq!(t.hints, 0);
+    }
+}
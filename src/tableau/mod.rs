@@ -1,6 +1,10 @@
 pub mod node;
 pub mod solver;
 pub mod printer;
+pub mod rules;
+pub mod manual;
+pub mod certificate;
+pub mod stats;
 
-pub use solver::build_tableau;
+pub use solver::{build_tableau, build_tableau_with_limits};
 pub use printer::print_tree;
@@ -1,17 +1,27 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::formula::Formula;
 
-#[derive(Debug, PartialEq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum NodeStatus {
     Open,
     Closed,
     Intermediate,
+    // Frunză la care construcția s-a oprit din cauza unei limite
+    Unknown,
 }
 
-#[derive(Debug, Clone, Serialize)]
+// Justificarea unui nod: formula descompusă (cu numele regulii α/β) sau perechea care închide ramura
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Annotation {
+    Expansion { formula: Formula, rule: String },
+    Closure { left: Formula, right: Formula },
+}
+
+#[derive(Debug, Clone)]
 pub struct TableauNode {
     pub formulas: Vec<Formula>,
     pub children: Vec<TableauNode>,
     pub status: NodeStatus,
+    pub annotation: Option<Annotation>,
 }
@@ -14,6 +14,7 @@ pub fn print_tree(node: &TableauNode, prefix: String, is_last: bool) {
         NodeStatus::Closed => " [❌ ÎNCHIS]".red().bold(),
         NodeStatus::Open => " [✅ DESCHIS]".green().bold(),
         NodeStatus::Intermediate => "".normal(),
+        NodeStatus::Unknown => " [❓ NEEXPLORAT]".yellow().bold(),
     };
 
     println!("{}{}{}{}", prefix, marker, forms_str, status_str);
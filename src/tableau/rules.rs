@@ -0,0 +1,48 @@
+use crate::formula::Formula;
+
+// Tipul regulii: α adaugă componentele pe aceeași ramură, β desparte ramura
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleKind {
+    Alpha,
+    Beta,
+}
+
+// Rezultatul aplicării unei reguli: pentru fiecare ramură nouă, formulele adăugate
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    pub kind: RuleKind,
+    pub name: &'static str,
+    pub branches: Vec<Vec<Formula>>,
+}
+
+// Regula care se aplică formulei; None pentru literali (nu mai pot fi descompuși)
+pub fn expand(f: &Formula) -> Option<Expansion> {
+    let alpha = |name, parts: Vec<Formula>| Expansion { kind: RuleKind::Alpha, name, branches: vec![parts] };
+    let beta = |name, a: Formula, b: Formula| Expansion { kind: RuleKind::Beta, name, branches: vec![vec![a], vec![b]] };
+
+    Some(match f {
+        Formula::Var(_) => return None,
+        Formula::And(a, b) => alpha("α: A ∧ B", vec![(**a).clone(), (**b).clone()]),
+        Formula::Or(a, b) => beta("β: A ∨ B", (**a).clone(), (**b).clone()),
+        Formula::Implies(a, b) => beta("β: A → B", Formula::not((**a).clone()), (**b).clone()),
+        Formula::Not(inner) => match &**inner {
+            Formula::Var(_) => return None,
+            Formula::Not(a) => alpha("α: ¬¬A", vec![(**a).clone()]),
+            Formula::Or(a, b) => alpha("α: ¬(A ∨ B)", vec![Formula::not((**a).clone()), Formula::not((**b).clone())]),
+            Formula::Implies(a, b) => alpha("α: ¬(A → B)", vec![(**a).clone(), Formula::not((**b).clone())]),
+            Formula::And(a, b) => beta("β: ¬(A ∧ B)", Formula::not((**a).clone()), Formula::not((**b).clone())),
+        },
+    })
+}
+
+// O pereche de formule contradictorii (A și ¬A) pe o ramură, dacă există
+pub fn find_contradiction(formulas: &[Formula]) -> Option<(usize, usize)> {
+    for (i, f1) in formulas.iter().enumerate() {
+        for (j, f2) in formulas.iter().enumerate().skip(i + 1) {
+            if f1.is_contradiction_with(f2) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
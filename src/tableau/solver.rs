@@ -1,116 +1,170 @@
 use crate::formula::Formula;
-use super::node::{TableauNode, NodeStatus};
+use super::node::{Annotation, TableauNode, NodeStatus};
+use super::rules;
+use super::stats::{Limit, Limits, TableauStats};
+use std::time::Instant;
 
 pub fn build_tableau(formulas: Vec<Formula>) -> TableauNode {
-    for (i, f1) in formulas.iter().enumerate() {
-        for f2 in formulas.iter().skip(i + 1) {
-            if f1.is_literal() && f2.is_literal() && f1.is_contradiction_with(f2) {
-                return TableauNode {
-                    formulas,
-                    children: vec![],
-                    status: NodeStatus::Closed,
-                };
-            }
+    build_tableau_with_limits(formulas, &Limits::default()).0
+}
+
+// Construiește tabloul cât timp limitele o permit; ramurile oprite rămân frunze Unknown
+pub fn build_tableau_with_limits(formulas: Vec<Formula>, limits: &Limits) -> (TableauNode, TableauStats) {
+    let mut builder = Builder { limits, start: Instant::now(), nodes: 0, hit: None };
+    let root = builder.build(formulas, 0);
+    let stats = TableauStats::collect(&root, builder.start.elapsed(), builder.hit);
+    (root, stats)
+}
+
+struct Builder<'a> {
+    limits: &'a Limits,
+    start: Instant,
+    nodes: usize,
+    hit: Option<Limit>,
+}
+
+impl Builder<'_> {
+    // Prima limită depășită; după o limită de noduri sau de timp nu se mai construiește nimic
+    fn exceeded(&mut self, depth: usize) -> Option<Limit> {
+        if matches!(self.hit, Some(Limit::Nodes | Limit::Time)) {
+            return self.hit;
         }
+        let limit = if self.limits.max_nodes.is_some_and(|max| self.nodes >= max) {
+            Limit::Nodes
+        } else if self.limits.timeout.is_some_and(|max| self.start.elapsed() >= max) {
+            Limit::Time
+        } else if self.limits.max_depth.is_some_and(|max| depth > max) {
+            Limit::Depth
+        } else {
+            return None;
+        };
+        self.hit.get_or_insert(limit);
+        Some(limit)
     }
 
-    let mut next_formulas = formulas.clone();
-    let index_opt = next_formulas.iter().position(|f| !f.is_literal());
-
-    if let Some(idx) = index_opt {
-        let current = next_formulas.remove(idx);
-        let mut children = Vec::new();
-
-match current.clone() {
-            // ---------------------------------------------------------
-            // 1. REGULI ALPHA (O singură ramură - "AND-like")
-            // ---------------------------------------------------------
-            
-            // A & B  -> Adaugă A, Adaugă B
-            Formula::And(a, b) => {
-                let mut path = next_formulas;
-                path.push(*a); path.push(*b);
-                children.push(build_tableau(path));
-            },
-            
-            // !(A v B) -> Adaugă !A, Adaugă !B (De Morgan)
-            Formula::Not(bx) if matches!(*bx, Formula::Or(_, _)) => {
-                if let Formula::Or(a, b) = *bx {
-                    let mut path = next_formulas;
-                    path.push(Formula::not(*a)); path.push(Formula::not(*b));
-                    children.push(build_tableau(path));
-                }
-            },
-            
-            // !(A -> B) -> Adaugă A, Adaugă !B  <-- ASTA ÎȚI LIPSEA PENTRU (P -> P)
-            Formula::Not(bx) if matches!(*bx, Formula::Implies(_, _)) => {
-                if let Formula::Implies(a, b) = *bx {
-                    let mut path = next_formulas;
-                    path.push(*a);               // Ipoteza devine adevărată
-                    path.push(Formula::not(*b)); // Concluzia devine falsă
-                    children.push(build_tableau(path));
+    fn build(&mut self, formulas: Vec<Formula>, depth: usize) -> TableauNode {
+        if self.exceeded(depth).is_some() {
+            return TableauNode { formulas, children: vec![], status: NodeStatus::Unknown, annotation: None };
+        }
+        self.nodes += 1;
+
+        for (i, f1) in formulas.iter().enumerate() {
+            for f2 in formulas.iter().skip(i + 1) {
+                if f1.is_literal() && f2.is_literal() && f1.is_contradiction_with(f2) {
+                    let annotation = Some(Annotation::Closure { left: f1.clone(), right: f2.clone() });
+                    return TableauNode {
+                        formulas,
+                        children: vec![],
+                        status: NodeStatus::Closed,
+                        annotation,
+                    };
                 }
-            },
-            
-            // !!A -> Adaugă A (Dubla negație) <-- ASTA ÎȚI LIPSEA PENTRU (P | !P)
-            Formula::Not(bx) if matches!(*bx, Formula::Not(_)) => {
-                if let Formula::Not(a) = *bx {
+            }
+        }
+
+        let mut next_formulas = formulas.clone();
+        let index_opt = next_formulas.iter().position(|f| !f.is_literal());
+
+        if let Some(idx) = index_opt {
+            let current = next_formulas.remove(idx);
+            let mut children = Vec::new();
+            let rule = rules::expand(&current).map_or("", |e| e.name).to_string();
+            let annotation = Some(Annotation::Expansion { formula: current.clone(), rule });
+
+            match current.clone() {
+                // ---------------------------------------------------------
+                // 1. REGULI ALPHA (O singură ramură - "AND-like")
+                // ---------------------------------------------------------
+
+                // A & B  -> Adaugă A, Adaugă B
+                Formula::And(a, b) => {
                     let mut path = next_formulas;
-                    path.push(*a);
-                    children.push(build_tableau(path));
-                }
-            },
-
-            // ---------------------------------------------------------
-            // 2. REGULI BETA (Ramificare - "OR-like")
-            // ---------------------------------------------------------
-            
-            // A v B -> Ramură cu A | Ramură cu B
-            Formula::Or(a, b) => {
-                let mut p1 = next_formulas.clone(); p1.push(*a);
-                let mut p2 = next_formulas;         p2.push(*b);
-                children.push(build_tableau(p1));
-                children.push(build_tableau(p2));
-            },
-            
-            // A -> B -> Ramură cu !A | Ramură cu B
-            Formula::Implies(a, b) => {
-                let mut p1 = next_formulas.clone(); p1.push(Formula::not(*a));
-                let mut p2 = next_formulas;         p2.push(*b);
-                children.push(build_tableau(p1));
-                children.push(build_tableau(p2));
-            },
-            
-            // !(A & B) -> Ramură cu !A | Ramură cu !B (De Morgan)
-            Formula::Not(bx) if matches!(*bx, Formula::And(_, _)) => {
-                if let Formula::And(a, b) = *bx {
+                    path.push(*a); path.push(*b);
+                    children.push(self.build(path, depth + 1));
+                },
+
+                // !(A v B) -> Adaugă !A, Adaugă !B (De Morgan)
+                Formula::Not(bx) if matches!(*bx, Formula::Or(_, _)) => {
+                    if let Formula::Or(a, b) = *bx {
+                        let mut path = next_formulas;
+                        path.push(Formula::not(*a)); path.push(Formula::not(*b));
+                        children.push(self.build(path, depth + 1));
+                    }
+                },
+
+                // !(A -> B) -> Adaugă A, Adaugă !B  <-- ASTA ÎȚI LIPSEA PENTRU (P -> P)
+                Formula::Not(bx) if matches!(*bx, Formula::Implies(_, _)) => {
+                    if let Formula::Implies(a, b) = *bx {
+                        let mut path = next_formulas;
+                        path.push(*a);               // Ipoteza devine adevărată
+                        path.push(Formula::not(*b)); // Concluzia devine falsă
+                        children.push(self.build(path, depth + 1));
+                    }
+                },
+
+                // !!A -> Adaugă A (Dubla negație) <-- ASTA ÎȚI LIPSEA PENTRU (P | !P)
+                Formula::Not(bx) if matches!(*bx, Formula::Not(_)) => {
+                    if let Formula::Not(a) = *bx {
+                        let mut path = next_formulas;
+                        path.push(*a);
+                        children.push(self.build(path, depth + 1));
+                    }
+                },
+
+                // ---------------------------------------------------------
+                // 2. REGULI BETA (Ramificare - "OR-like")
+                // ---------------------------------------------------------
+
+                // A v B -> Ramură cu A | Ramură cu B
+                Formula::Or(a, b) => {
+                    let mut p1 = next_formulas.clone(); p1.push(*a);
+                    let mut p2 = next_formulas;         p2.push(*b);
+                    children.push(self.build(p1, depth + 1));
+                    children.push(self.build(p2, depth + 1));
+                },
+
+                // A -> B -> Ramură cu !A | Ramură cu B
+                Formula::Implies(a, b) => {
                     let mut p1 = next_formulas.clone(); p1.push(Formula::not(*a));
-                    let mut p2 = next_formulas;         p2.push(Formula::not(*b));
-                    children.push(build_tableau(p1));
-                    children.push(build_tableau(p2));
-                }
-            },
+                    let mut p2 = next_formulas;         p2.push(*b);
+                    children.push(self.build(p1, depth + 1));
+                    children.push(self.build(p2, depth + 1));
+                },
 
-            _ => {}
-        }        
-let computed_status = if children.iter().all(|c| c.status == NodeStatus::Closed) {
-            NodeStatus::Closed
-        } else {
-            // Dacă măcar un copil e deschis (sau intermediar), nodul curent nu e închis.
-            NodeStatus::Intermediate 
-        };
+                // !(A & B) -> Ramură cu !A | Ramură cu !B (De Morgan)
+                Formula::Not(bx) if matches!(*bx, Formula::And(_, _)) => {
+                    if let Formula::And(a, b) = *bx {
+                        let mut p1 = next_formulas.clone(); p1.push(Formula::not(*a));
+                        let mut p2 = next_formulas;         p2.push(Formula::not(*b));
+                        children.push(self.build(p1, depth + 1));
+                        children.push(self.build(p2, depth + 1));
+                    }
+                },
 
-        return TableauNode {
-            formulas,
-            children,
-            status: computed_status, // Aici era hardcodat Intermediate înainte
-        };
-    }
+                _ => {}
+            }
+
+            let computed_status = if children.iter().all(|c| c.status == NodeStatus::Closed) {
+                NodeStatus::Closed
+            } else {
+                // Dacă măcar un copil e deschis (sau intermediar), nodul curent nu e închis.
+                NodeStatus::Intermediate
+            };
 
-    // Asta e pentru nodurile frunză care nu au contradicții (rămân Open)
-    TableauNode {
-        formulas,
-        children: vec![],
-        status: NodeStatus::Open,
+            return TableauNode {
+                formulas,
+                children,
+                status: computed_status, // Aici era hardcodat Intermediate înainte
+                annotation,
+            };
+        }
+
+        // Asta e pentru nodurile frunză care nu au contradicții (rămân Open)
+        TableauNode {
+            formulas,
+            children: vec![],
+            status: NodeStatus::Open,
+            annotation: None,
+        }
     }
 }
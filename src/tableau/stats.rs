@@ -0,0 +1,195 @@
+use colored::*;
+use std::fmt;
+use std::time::Duration;
+
+use super::node::{NodeStatus, TableauNode};
+
+// Limitele construcției; None înseamnă fără limită
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Limits {
+    pub max_nodes: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub timeout: Option<Duration>,
+}
+
+impl Limits {
+    // Limitele implicite ale meniului interactiv: destul pentru exercițiile obișnuite,
+    // dar o formulă uriașă nu mai blochează programul
+    pub fn interactive() -> Self {
+        Limits {
+            max_nodes: Some(100_000),
+            max_depth: Some(1_000),
+            timeout: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+// Limita care a oprit construcția
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Nodes,
+    Depth,
+    Time,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Nodes => write!(f, "numărul maxim de noduri"),
+            Limit::Depth => write!(f, "adâncimea maximă"),
+            Limit::Time => write!(f, "timpul maxim"),
+        }
+    }
+}
+
+// Verdictul tabloului: o ramură deschisă și completă e un model chiar dacă alte ramuri au fost oprite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Unsatisfiable,
+    Satisfiable,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableauStats {
+    pub nodes: usize,
+    pub branches: usize,
+    pub open_leaves: usize,
+    pub closed_leaves: usize,
+    pub unknown_leaves: usize,
+    pub max_depth: usize,
+    pub rule_applications: usize,
+    pub elapsed: Duration,
+    pub limit: Option<Limit>,
+}
+
+impl TableauStats {
+    pub fn collect(root: &TableauNode, elapsed: Duration, limit: Option<Limit>) -> Self {
+        let mut stats = TableauStats {
+            nodes: 0, branches: 0, open_leaves: 0, closed_leaves: 0, unknown_leaves: 0,
+            max_depth: 0, rule_applications: 0, elapsed, limit,
+        };
+        stats.visit(root, 0);
+        stats
+    }
+
+    // Frunzele Unknown nu au fost construite (limita a oprit construcția înaintea lor), deci
+    // sunt numărate doar ca ramuri neexplorate, nu ca noduri: `nodes` nu depășește `max_nodes`
+    fn visit(&mut self, node: &TableauNode, depth: usize) {
+        if node.status == NodeStatus::Unknown && node.children.is_empty() {
+            self.branches += 1;
+            self.unknown_leaves += 1;
+            return;
+        }
+        self.nodes += 1;
+        self.max_depth = self.max_depth.max(depth);
+        if node.children.is_empty() {
+            self.branches += 1;
+            match node.status {
+                NodeStatus::Closed => self.closed_leaves += 1,
+                _ => self.open_leaves += 1,
+            }
+        } else {
+            self.rule_applications += 1;
+        }
+        for child in &node.children {
+            self.visit(child, depth + 1);
+        }
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        if self.open_leaves > 0 {
+            Verdict::Satisfiable
+        } else if self.unknown_leaves > 0 {
+            Verdict::Unknown
+        } else {
+            Verdict::Unsatisfiable
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", format!("Tablou: {} noduri, {} ramuri ({} deschise, {} închise, {} neexplorate), adâncime {}, {} aplicări de reguli, {:.3} ms",
+            self.nodes, self.branches, self.open_leaves, self.closed_leaves, self.unknown_leaves,
+            self.max_depth, self.rule_applications, self.elapsed.as_secs_f64() * 1000.0).dimmed());
+        if let Some(limit) = self.limit {
+            println!("{}", format!("Construcția a fost oprită: s-a atins {}.", limit).yellow().bold());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula::Formula;
+    use crate::parser::parse_formula;
+    use crate::tableau::solver::build_tableau_with_limits;
+
+    // a1 ∨ b1, ..., an ∨ bn, ¬a1, ..., ¬an, ¬bn: fiecare disjuncție ramifică, toate ramurile se închid
+    fn wide(n: usize) -> Vec<Formula> {
+        let mut formulas: Vec<Formula> = (1..=n).map(|i| parse_formula(&format!("a{} | b{}", i, i))).collect();
+        formulas.extend((1..=n).map(|i| parse_formula(&format!("!a{}", i))));
+        formulas.push(parse_formula(&format!("!b{}", n)));
+        formulas
+    }
+
+    #[test]
+    fn unlimited_tableau_has_a_verdict() {
+        let (_, stats) = build_tableau_with_limits(wide(4), &Limits::default());
+        assert_eq!(stats.verdict(), Verdict::Unsatisfiable);
+        assert_eq!(stats.limit, None);
+        assert_eq!(stats.unknown_leaves, 0);
+        assert_eq!(stats.branches, stats.closed_leaves);
+        assert_eq!(stats.nodes, stats.branches + stats.rule_applications);
+
+        let (_, stats) = build_tableau_with_limits(vec![parse_formula("p | q")], &Limits::default());
+        assert_eq!(stats.verdict(), Verdict::Satisfiable);
+        assert_eq!((stats.nodes, stats.branches, stats.open_leaves), (3, 2, 2));
+    }
+
+    #[test]
+    fn node_limit_is_never_exceeded() {
+        for max in [1, 2, 5, 10] {
+            let limits = Limits { max_nodes: Some(max), ..Limits::default() };
+            let (_, stats) = build_tableau_with_limits(wide(8), &limits);
+            assert!(stats.nodes <= max, "{} noduri pentru limita {}", stats.nodes, max);
+            assert_eq!(stats.limit, Some(Limit::Nodes));
+            assert!(stats.unknown_leaves > 0);
+            assert_eq!(stats.verdict(), Verdict::Unknown);
+        }
+    }
+
+    #[test]
+    fn depth_limit_stops_every_deep_branch() {
+        let limits = Limits { max_depth: Some(3), ..Limits::default() };
+        let (_, stats) = build_tableau_with_limits(wide(6), &limits);
+        assert!(stats.max_depth <= 3);
+        assert_eq!(stats.limit, Some(Limit::Depth));
+        assert_eq!(stats.verdict(), Verdict::Unknown);
+    }
+
+    #[test]
+    fn zero_timeout_builds_nothing() {
+        let limits = Limits { timeout: Some(Duration::ZERO), ..Limits::default() };
+        let (root, stats) = build_tableau_with_limits(wide(3), &limits);
+        assert_eq!(root.status, NodeStatus::Unknown);
+        assert_eq!((stats.nodes, stats.branches, stats.unknown_leaves), (0, 1, 1));
+        assert_eq!(stats.limit, Some(Limit::Time));
+    }
+
+    #[test]
+    fn open_branch_decides_despite_limits() {
+        // Prima ramură (p) e deschisă și completă: formula e satisfiabilă chiar dacă restul e oprit
+        let limits = Limits { max_nodes: Some(3), ..Limits::default() };
+        let (_, stats) = build_tableau_with_limits(vec![parse_formula("p | ((q | r) & (s | t) & (u | w))")], &limits);
+        assert!(stats.open_leaves > 0);
+        assert_eq!(stats.verdict(), Verdict::Satisfiable);
+    }
+
+    #[test]
+    fn interactive_limits_are_finite() {
+        let limits = Limits::interactive();
+        assert!(limits.max_nodes.is_some_and(|n| n > 0));
+        assert!(limits.max_depth.is_some_and(|d| d > 0));
+        assert!(limits.timeout.is_some_and(|t| !t.is_zero()));
+    }
+}
@@ -0,0 +1,205 @@
+use colored::*;
+
+use crate::formula::{Formula, Valuation};
+
+// Peste atâtea variabile, tabelul (2^n rânduri) nu mai e construit
+pub const MAX_TABLE_VARS: usize = 16;
+
+// Tabelul de adevăr cu câte o coloană pentru fiecare subformulă, ca la seminar:
+// întâi variabilele, apoi subformulele de la cele mai mici la formula întreagă.
+pub struct TruthTable {
+    pub variables: Vec<String>,
+    pub columns: Vec<Formula>,
+    // Pe fiecare rând: valorile variabilelor, apoi valorile coloanelor
+    pub rows: Vec<Vec<bool>>,
+}
+
+fn collect_subformulas(f: &Formula, out: &mut Vec<Formula>) {
+    match f {
+        Formula::Var(_) => return,
+        Formula::Not(inner) => collect_subformulas(inner, out),
+        Formula::And(l, r) | Formula::Or(l, r) | Formula::Implies(l, r) => {
+            collect_subformulas(l, out);
+            collect_subformulas(r, out);
+        }
+    }
+    if !out.contains(f) {
+        out.push(f.clone());
+    }
+}
+
+impl TruthTable {
+    pub fn new(formula: &Formula) -> Result<Self, String> {
+        let variables = formula.variables();
+        let n = variables.len();
+        if n > MAX_TABLE_VARS {
+            return Err(format!("Tabelul ar avea 2^{} rânduri (limita e {} variabile).", n, MAX_TABLE_VARS));
+        }
+
+        let mut columns = Vec::new();
+        collect_subformulas(formula, &mut columns);
+
+        let rows = (0..1usize << n)
+            .map(|idx| {
+                let valuation: Valuation = variables.iter().enumerate()
+                    .map(|(i, v)| (v.clone(), (idx >> (n - 1 - i)) & 1 == 1))
+                    .collect();
+                variables.iter().map(|v| valuation[v])
+                    .chain(columns.iter().map(|c| c.eval(&valuation)))
+                    .collect()
+            })
+            .collect();
+        Ok(TruthTable { variables, columns, rows })
+    }
+
+    fn headers(&self) -> Vec<String> {
+        self.variables.iter().cloned().chain(self.columns.iter().map(|c| c.to_string())).collect()
+    }
+
+    // Valorile formulei întregi (ultima coloană)
+    fn results(&self) -> impl Iterator<Item = bool> + '_ {
+        self.rows.iter().map(|r| *r.last().expect("Rând gol"))
+    }
+
+    pub fn print(&self) {
+        let headers = self.headers();
+        let widths: Vec<usize> = headers.iter().map(|h| h.chars().count().max(1)).collect();
+        let last = headers.len() - 1;
+
+        let line: Vec<String> = headers.iter().enumerate()
+            .map(|(i, h)| if i == last { h.bold().yellow().to_string() } else { h.bold().to_string() })
+            .collect();
+        println!("{}", line.join(" │ "));
+        println!("{}", widths.iter().map(|&w| "─".repeat(w)).collect::<Vec<_>>().join("─┼─"));
+
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().zip(&widths).enumerate()
+                .map(|(i, (&v, &w))| {
+                    let text = format!("{:^w$}", v as u8, w = w);
+                    let colored = if v { text.green() } else { text.red() };
+                    if i == last { colored.bold().to_string() } else { colored.to_string() }
+                })
+                .collect();
+            println!("{}", cells.join(" │ "));
+        }
+
+        let models = self.results().filter(|&v| v).count();
+        let verdict = if models == self.rows.len() {
+            "TAUTOLOGIE (adevărată pe toate rândurile)".green()
+        } else if models == 0 {
+            "CONTRADICȚIE (falsă pe toate rândurile)".red()
+        } else {
+            format!("SATISFIABILĂ ({} din {} rânduri adevărate)", models, self.rows.len()).yellow()
+        };
+        println!("\nFormula este: {}", verdict.bold());
+    }
+
+    pub fn to_csv(&self) -> String {
+        let quote = |h: &String| if h.contains(',') || h.contains('"') { format!("\"{}\"", h.replace('"', "\"\"")) } else { h.clone() };
+        let mut csv = self.headers().iter().map(quote).collect::<Vec<_>>().join(",");
+        csv.push('\n');
+        for row in &self.rows {
+            csv.push_str(&row.iter().map(|&v| (v as u8).to_string()).collect::<Vec<_>>().join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let headers = self.headers();
+        let mut md = format!("| {} |\n", headers.iter().map(|h| h.replace('|', "\\|")).collect::<Vec<_>>().join(" | "));
+        md.push_str(&format!("|{}\n", ":-:|".repeat(headers.len())));
+        for row in &self.rows {
+            md.push_str(&format!("| {} |\n", row.iter().map(|&v| (v as u8).to_string()).collect::<Vec<_>>().join(" | ")));
+        }
+        md
+    }
+
+    // Mediul `tabular`, cu o linie verticală între variabile și subformule
+    pub fn to_latex(&self) -> String {
+        let n = self.variables.len();
+        let spec = format!("{}|{}", "c".repeat(n), "c".repeat(self.columns.len()));
+        let headers: Vec<String> = self.variables.iter().map(|v| format!("${}$", latex_name(v)))
+            .chain(self.columns.iter().map(|c| format!("${}$", latex_formula(c, true))))
+            .collect();
+
+        let mut tex = format!("\\begin{{tabular}}{{{}}}\n", spec);
+        tex.push_str(&format!("{} \\\\\n\\hline\n", headers.join(" & ")));
+        for row in &self.rows {
+            tex.push_str(&format!("{} \\\\\n", row.iter().map(|&v| (v as u8).to_string()).collect::<Vec<_>>().join(" & ")));
+        }
+        tex.push_str("\\end{tabular}\n");
+        tex
+    }
+}
+
+fn latex_name(v: &str) -> String {
+    v.replace('_', "\\_")
+}
+
+pub fn latex_formula(f: &Formula, top: bool) -> String {
+    let wrap = |s: String| if top { s } else { format!("({})", s) };
+    match f {
+        Formula::Var(n) => latex_name(n),
+        Formula::Not(inner) => format!("\\neg {}", latex_formula(inner, false)),
+        Formula::And(l, r) => wrap(format!("{} \\land {}", latex_formula(l, false), latex_formula(r, false))),
+        Formula::Or(l, r) => wrap(format!("{} \\lor {}", latex_formula(l, false), latex_formula(r, false))),
+        Formula::Implies(l, r) => wrap(format!("{} \\to {}", latex_formula(l, false), latex_formula(r, false))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_formula;
+
+    #[test]
+    fn variables_in_first_occurrence_order() {
+        assert_eq!(parse_formula("(q -> p) & (r | q)").variables(), vec!["q", "p", "r"]);
+    }
+
+    #[test]
+    fn eval_under_a_valuation() {
+        let f = parse_formula("(p -> q) & !r");
+        let valuation = |p, q, r| Valuation::from([("p".to_string(), p), ("q".to_string(), q), ("r".to_string(), r)]);
+        assert!(f.eval(&valuation(false, false, false)));
+        assert!(!f.eval(&valuation(true, false, false)));
+        assert!(!f.eval(&valuation(true, true, true)));
+        // Variabilele lipsă sunt false
+        assert!(f.eval(&Valuation::new()));
+    }
+
+    #[test]
+    fn subformula_columns_and_rows() {
+        let table = TruthTable::new(&parse_formula("!p -> (p & q)")).unwrap();
+        let columns: Vec<String> = table.columns.iter().map(|c| c.to_string()).collect();
+        assert_eq!(columns, vec!["¬p", "(p ∧ q)", "(¬p → (p ∧ q))"]);
+        assert_eq!(table.rows.len(), 4);
+        // Rândurile: p, q, ¬p, p∧q, formula
+        assert_eq!(table.rows[0], vec![false, false, true, false, false]);
+        assert_eq!(table.rows[3], vec![true, true, false, true, true]);
+        assert_eq!(table.results().filter(|&v| v).count(), 2);
+    }
+
+    #[test]
+    fn wide_formulas_are_refused() {
+        let conjunction = |n: usize| (0..n).map(|i| format!("x{}", i)).collect::<Vec<_>>().join(" & ");
+        assert_eq!(TruthTable::new(&parse_formula(&conjunction(MAX_TABLE_VARS))).unwrap().rows.len(), 1 << MAX_TABLE_VARS);
+        let error = TruthTable::new(&parse_formula(&conjunction(MAX_TABLE_VARS + 1))).err().unwrap();
+        assert!(error.contains("2^17"));
+        // 64 de variabile: fără limită, `1usize << 64` ar da panică
+        assert!(TruthTable::new(&parse_formula(&conjunction(64))).is_err());
+    }
+
+    #[test]
+    fn exports() {
+        let table = TruthTable::new(&parse_formula("p | q")).unwrap();
+        assert_eq!(table.to_csv(), "p,q,(p ∨ q)\n0,0,0\n0,1,1\n1,0,1\n1,1,1\n");
+        let md = table.to_markdown();
+        assert!(md.starts_with("| p | q | (p ∨ q) |\n|:-:|:-:|:-:|\n"));
+        assert_eq!(md.lines().count(), 6);
+        let tex = table.to_latex();
+        assert!(tex.starts_with("\\begin{tabular}{cc|c}\n$p$ & $q$ & $p \\lor q$ \\\\\n\\hline\n"));
+        assert!(tex.ends_with("1 & 1 & 1 \\\\\n\\end{tabular}\n"));
+    }
+}
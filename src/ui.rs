@@ -31,15 +31,31 @@ pub fn read_variables() -> Vec<String> {
 // Cere mintermii (liniile unde funcția e 1)
 pub fn read_minterms(num_vars: usize) -> Vec<Vec<u8>> {
     println!("{}", "Introdu valorile pentru care funcția este 1 (ex: 0 0 1).".yellow());
+    read_rows("Minterm", num_vars, false)
+}
+
+// Cere maxtermii (liniile unde funcția e 0)
+pub fn read_maxterms(num_vars: usize) -> Vec<Vec<u8>> {
+    println!("{}", "Introdu valorile pentru care funcția este 0 (ex: 0 0 1).".yellow());
+    read_rows("Maxterm", num_vars, false)
+}
+
+// Cere liniile indiferente (don't care); o linie goală încheie citirea
+pub fn read_dont_cares(num_vars: usize) -> Vec<Vec<u8>> {
+    println!("{}", "Introdu liniile indiferente / don't care (opțional, Enter pentru niciuna).".yellow());
+    read_rows("Don't care", num_vars, true)
+}
+
+fn read_rows(label: &str, num_vars: usize, empty_ends: bool) -> Vec<Vec<u8>> {
     println!("Scrie '{}' când ai terminat.", "gata".bold());
 
-    let mut minterms = Vec::new();
+    let mut rows = Vec::new();
 
     loop {
-        let prompt = format!("Minterm ({} biți) > ", num_vars);
+        let prompt = format!("{} ({} biți) > ", label, num_vars);
         let line = read_line(&prompt);
 
-        if line.eq_ignore_ascii_case("gata") {
+        if line.eq_ignore_ascii_case("gata") || (empty_ends && line.is_empty()) {
             break;
         }
 
@@ -53,7 +69,7 @@ pub fn read_minterms(num_vars: usize) -> Vec<Vec<u8>> {
             continue;
         }
 
-        minterms.push(bits);
+        rows.push(bits);
     }
-    minterms
+    rows
 }